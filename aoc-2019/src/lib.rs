@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use crate::Day3WireSegmentDirection::{WireRight, WireLeft, WireDown, WireUp};
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_common::grid::{Direction, Point};
+use aoc_common::parse::{parse, Csv, Lines};
+use aoc_common::sparse::SparseGrid;
+use aoc_common::{puzzle, Year};
+
+#[allow(clippy::needless_return, clippy::needless_late_init)]
+mod intcode_computer;
+
+/// All of the 2019 puzzles that have been solved so far
+pub fn year() -> Year {
+    #[cfg(feature = "embed")]
+    aoc_common::input::embed(include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs")));
+
+    Year::new(2019, env!("CARGO_MANIFEST_DIR"), vec![
+        puzzle!(1, "The Tyranny of the Rocket Equation", day1_parse, day1_part1, day1_part2),
+        puzzle!(2, "1202 Program Alarm", read_file_to_vector, day2_part1, day2_part2),
+        puzzle!(3, "Crossed Wires", day3_parse, day3_part1, day3_part2),
+        puzzle!(4, "Secure Container", day4_parse, day4_part1, day4_part2),
+    ])
+}
+
+fn module_fuel(mass: i32) -> i32 {
+    (mass / 3).saturating_sub(2)
+}
+
+fn module_fuel_all(mass: i32) -> i32 {
+    if mass <= 0 {
+        0
+    } else {
+        let mut fuel = module_fuel(mass);
+        let fuel_for_fuel = module_fuel_all(fuel);
+
+        if fuel_for_fuel > 0 {
+            fuel += module_fuel_all(fuel);
+        }
+        fuel
+    }
+}
+
+fn day1_parse(input: &str) -> Vec<i32> {
+    parse::<Lines<i32>>(input).0
+}
+
+fn day1_part1(day1_input_vec: &[i32]) -> i32 {
+    day1_input_vec.iter().cloned().map(module_fuel).sum()
+}
+
+fn day1_part2(day1_input_vec: &[i32]) -> i32 {
+    let mut total_mass_with_fuel: i32 = 0;
+    for mass in day1_input_vec {
+        total_mass_with_fuel += module_fuel_all(*mass);
+    }
+
+    total_mass_with_fuel
+}
+
+fn read_file_to_vector(input: &str) -> Vec<i32> {
+    parse::<Csv<i32>>(input).0
+}
+
+fn day2_part1(day2_original: &[i32]) -> i32 {
+    let mut day2_program: Vec<i32> = day2_original.to_vec();
+
+    // Change the program as instructed by AoC day 2 part 1
+    day2_program[1] = 12;
+    day2_program[2] = 2;
+
+    let mut computer = intcode_computer::IntcodeComputer::new();
+    computer.run_program(day2_program, 0)
+}
+
+fn day2_part2(day2_original: &[i32]) -> i32 {
+    let mut computer = intcode_computer::IntcodeComputer::new();
+    let mut program_output: i32;
+    let mut noun_final: i32 = 0;
+    let mut verb_final: i32 = 0;
+    'outer: for noun in 0..=99 {
+        for verb in 0..=99 {
+            let mut day2_program_test = day2_original.to_vec();
+            day2_program_test[1] = noun;
+            day2_program_test[2] = verb;
+            program_output = computer.run_program(day2_program_test, 0);
+
+            if program_output == 19690720 {
+                noun_final = noun;
+                verb_final = verb;
+                break 'outer;
+            }
+        }
+    }
+
+    100 * noun_final + verb_final
+}
+
+fn number_to_vec(n: u32) -> Vec<u32> {
+    n.to_string().chars().map(|c| c.to_digit(10).unwrap()).collect()
+}
+
+fn is_password_viable_part1(password: u32) -> bool {
+    let password_vec = number_to_vec(password);
+    let mut password_sorted = password_vec.clone();
+
+    // If the sorted password and the original password are the same, then the numbers are all in
+    // ascending order, thus passing the first test
+    password_sorted.sort();
+
+    let mut is_viable: bool = false;
+    if password_vec == password_sorted {
+        let mut previous_digit: u32 = 0;
+        for digit in password_vec {
+            if digit == previous_digit {
+                is_viable = true;
+                break;
+            }
+
+            previous_digit = digit;
+        }
+    }
+
+    is_viable
+}
+
+fn is_password_viable_part2(password: u32) -> bool {
+    let password_vec = number_to_vec(password);
+    let mut password_sorted = password_vec.clone();
+
+    // If the sorted password and the original password are the same, then the numbers are all in
+    // ascending order, thus passing the first test
+    password_sorted.sort();
+
+    let mut is_viable: bool = false;
+    if password_vec == password_sorted {
+        // Create a hashmap of digits and the number of times that digit is seen
+        let mut digit_map: HashMap<u32, u32> = HashMap::new();
+        for digit in password_vec {
+            let digit_counter = digit_map.entry(digit).or_insert(0);
+            *digit_counter += 1;
+        }
+
+        for count in digit_map.values() {
+            // If even a single digit has a count of 2, then the whole password is viable (after
+            // passing the sort test)
+            if *count == 2u32 {
+                is_viable = true;
+                break;
+            }
+        }
+    }
+
+    is_viable
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Day3WireSegmentDirection {
+    WireRight,
+    WireLeft,
+    WireUp,
+    WireDown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ParsedWireSegment {
+    direction: Day3WireSegmentDirection,
+    length: i32,
+}
+
+impl FromStr for ParsedWireSegment {
+    type Err = String;
+
+    /// A direction letter followed by a length, such as "R75"
+    fn from_str(segment: &str) -> Result<ParsedWireSegment, String> {
+        let mut chars = segment.chars();
+
+        let direction = match chars.next() {
+            Some('R') => WireRight,
+            Some('L') => WireLeft,
+            Some('U') => WireUp,
+            Some('D') => WireDown,
+            Some(other) => return Err(format!("unknown direction {:?}", other)),
+            None => return Err("empty wire segment".to_string()),
+        };
+
+        let length = chars.as_str().parse::<i32>().map_err(|err| format!("invalid length: {}", err))?;
+
+        Ok(ParsedWireSegment { direction, length })
+    }
+}
+
+/// Follows a wire's path from the origin, calling the function with each cell the wire passes through and the number
+/// of steps it took to get there
+fn day3_follow_wire(wire: &[ParsedWireSegment], mut visit: impl FnMut(Point, i32)) {
+    let mut position = Point::ORIGIN;
+    let mut steps = 0;
+
+    for parsed_segment in wire {
+        let direction = match parsed_segment.direction {
+            WireUp => Direction::North,
+            WireDown => Direction::South,
+            WireRight => Direction::East,
+            WireLeft => Direction::West,
+        };
+
+        for _ in 0..parsed_segment.length {
+            position = position.step(direction);
+            steps += 1;
+            visit(position, steps);
+        }
+    }
+}
+
+/// The two wires, each as the list of segments along its path
+type Wires = (Vec<ParsedWireSegment>, Vec<ParsedWireSegment>);
+
+/// Each line of the input is one wire's path, as a comma separated list of moves
+fn day3_parse(input: &str) -> Wires {
+    let Lines(wires) = parse::<Lines<Csv<ParsedWireSegment>>>(input);
+
+    match <[Csv<ParsedWireSegment>; 2]>::try_from(wires) {
+        Ok([Csv(wire1), Csv(wire2)]) => (wire1, wire2),
+        Err(wires) => panic!("Expected 2 wires, found {}", wires.len()),
+    }
+}
+
+/// Finds every point where the two wires cross. Returns the Manhattan distance of each crossing from the origin
+/// along with the combined number of steps both wires take to reach it.
+fn day3_find_intersections(wires: &Wires) -> (Vec<i32>, Vec<i32>) {
+    // Mark where the first wire goes with the fewest steps it took to get there
+    let mut wire1_cells: SparseGrid<i32> = SparseGrid::new(0);
+    day3_follow_wire(&wires.0, |point, steps| {
+        if !wire1_cells.contains(point) {
+            wire1_cells.insert(point, steps);
+        }
+    });
+
+    let mut distances: Vec<i32> = vec![];
+    let mut step_distances: Vec<i32> = vec![];
+
+    // The wires both start at the origin, but it isn't marked so it doesn't count as a crossing. A crossing the second
+    // wire comes back to is counted again with more steps, which doesn't change the closest one.
+    day3_follow_wire(&wires.1, |point, wire2_steps| {
+        if let Some(wire1_steps) = wire1_cells.get(point) {
+            distances.push(point.manhattan_distance(Point::ORIGIN) as i32);
+            step_distances.push(wire1_steps + wire2_steps);
+        }
+    });
+
+    (distances, step_distances)
+}
+
+fn day3_part1(wires: &Wires) -> i32 {
+    let (mut distances, _) = day3_find_intersections(wires);
+
+    distances.sort();
+    *distances.first().unwrap()
+}
+
+fn day3_part2(wires: &Wires) -> i32 {
+    let (_, mut step_distances) = day3_find_intersections(wires);
+
+    step_distances.sort();
+    *step_distances.first().unwrap()
+}
+
+/// The range of possible passwords, given as "start-end"
+fn day4_parse(input: &str) -> RangeInclusive<u32> {
+    let (start, end) = input.trim().split_once('-').expect("Expected the password range as start-end");
+
+    parse::<u32>(start)..=parse::<u32>(end)
+}
+
+fn day4_part1(password_range: &RangeInclusive<u32>) -> u32 {
+    let mut viable_passwords = 0u32;
+    for password in password_range.clone() {
+        let is_viable = is_password_viable_part1(password);
+
+        if is_viable {
+            viable_passwords += 1;
+        }
+    }
+
+    viable_passwords
+}
+
+fn day4_part2(password_range: &RangeInclusive<u32>) -> u32 {
+    let mut viable_passwords = 0u32;
+
+    for password in password_range.clone() {
+        let is_viable = is_password_viable_part2(password);
+
+        if is_viable {
+            viable_passwords += 1;
+        }
+    }
+
+    viable_passwords
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use crate::{module_fuel, module_fuel_all};
+
+    #[test]
+    fn test_module_fuel() {
+        assert_eq!(module_fuel(12), 2);
+        assert_eq!(module_fuel(14), 2);
+        assert_eq!(module_fuel(1969), 654);
+        assert_eq!(module_fuel(100_756), 33583);
+    }
+
+    #[test]
+    fn test_module_fuel_all() {
+        assert_eq!(module_fuel_all(1969), 966);
+        assert_eq!(module_fuel_all(100756), 50346);
+    }
+}
+
+#[cfg(test)]
+mod day2_tests {
+    use crate::intcode_computer::IntcodeComputer;
+
+    #[test]
+    fn test_run_program() {
+        let mut computer = IntcodeComputer::new();
+        assert_eq!(computer.run_program(vec![1,9,10,3,2,3,11,0,99,30,40,50], 0), 3500);
+        assert_eq!(computer.run_program(vec![1,0,0,0,99], 0), 2);
+        assert_eq!(computer.run_program(vec![2,3,0,3,99], 3), 6);
+        assert_eq!(computer.run_program(vec![2,4,4,5,99,0], 5), 9801);
+        assert_eq!(computer.run_program(vec![1,1,1,4,99,5,6,0,99], 0), 30);
+    }
+}
+
+#[cfg(test)]
+mod day3_tests {
+    use aoc_common::parse::{Csv, FromInput, Lines};
+
+    use crate::ParsedWireSegment;
+    use crate::Day3WireSegmentDirection::{WireLeft, WireUp};
+
+    #[test]
+    fn test_parse_wire_segment() {
+        assert_eq!("U7".parse(), Ok(ParsedWireSegment { direction: WireUp, length: 7 }));
+        assert_eq!("L1003".parse(), Ok(ParsedWireSegment { direction: WireLeft, length: 1003 }));
+        assert!("X5".parse::<ParsedWireSegment>().is_err());
+        assert!("R".parse::<ParsedWireSegment>().is_err());
+        assert!("".parse::<ParsedWireSegment>().is_err());
+
+        let err = Lines::<Csv<ParsedWireSegment>>::from_input("R8,U5\nU7,X6\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
+
+#[cfg(test)]
+mod day4_tests {
+    use crate::{day4_parse, is_password_viable_part1, is_password_viable_part2};
+
+    #[test]
+    fn test_day4_parse() {
+        assert_eq!(day4_parse("372304-847060\n"), 372304..=847060);
+    }
+
+    #[test]
+    fn test_is_password_viable_part1() {
+        assert!(is_password_viable_part1(111111));
+        assert!(!is_password_viable_part1(223450));
+        assert!(!is_password_viable_part1(123789));
+    }
+
+    #[test]
+    fn test_is_password_viable_part2() {
+        assert!(is_password_viable_part2(112233));
+        assert!(!is_password_viable_part2(123444));
+        assert!(is_password_viable_part2(111122));
+    }
+}
+
+#[cfg(test)]
+mod example_tests {
+    aoc_common::example_tests!(crate::year(),
+        day1_example1,
+        day3_example1,
+        day3_example2,
+        day3_example3,
+    );
+}
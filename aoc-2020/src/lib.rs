@@ -0,0 +1,939 @@
+#[macro_use] extern crate lazy_static;
+extern crate regex;
+
+use std::collections::{HashMap, HashSet};
+use regex::Regex;
+use std::ops::{Add, Sub, SubAssign, AddAssign};
+use std::fmt::Display;
+
+use aoc_common::automaton::{Automaton, Neighbourhood, Neighbours};
+use aoc_common::grid::{Direction, Grid, Point};
+use aoc_common::parse::{parse, records, Lines};
+use aoc_common::path::bfs;
+use aoc_common::wrap::{Wrap, WrappingGrid};
+use aoc_common::{puzzle, Year};
+
+/// All of the 2020 puzzles that have been solved so far
+pub fn year() -> Year {
+    #[cfg(feature = "embed")]
+    aoc_common::input::embed(include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs")));
+
+    Year::new(2020, env!("CARGO_MANIFEST_DIR"), vec![
+        puzzle!(1, "Report Repair", day1_parse, day1_part1, day1_part2),
+        puzzle!(2, "Password Philosophy", read_lines, day2_part1, day2_part2),
+        puzzle!(3, "Toboggan Trajectory", day3_parse, day3_part1, day3_part2),
+        puzzle!(4, "Passport Processing", day4_parse, day4_part1, day4_part2),
+        puzzle!(5, "Binary Boarding", day5_parse, day5_part1, day5_part2),
+        puzzle!(6, "Custom Customs", day6_parse, day6_part1, day6_part2),
+        puzzle!(7, "Handy Haversacks", day7_parse, day7_part1, day7_part2),
+        puzzle!(8, "Handheld Halting", read_lines, day8_part1, day8_part2),
+        puzzle!(9, "Encoding Error", day9_parse, day9_part1, day9_part2),
+        puzzle!(10, "Adapter Array", day10_parse, day10_part1, day10_part2),
+        puzzle!(11, "Seating System", day11_parse, day11_part1, day11_part2).with_frames(day11_frames),
+        puzzle!(12, "Rain Risk", read_lines, day12_part1, day12_part2),
+        puzzle!(13, "Shuttle Search", read_lines, day13_part1),
+    ])
+}
+
+fn day1_parse(input: &str) -> Vec<i32> {
+    parse::<Lines<i32>>(input).0
+}
+
+fn day1_part1(day1_input_vec: &[i32]) -> i32 {
+    let mut product = 0;
+
+    // Borrow the day1_input_vec (with &) so as to not consume it and allow us to iterate on it on
+    // the inner loop as well
+    'outer: for expense1 in day1_input_vec {
+        for expense2 in day1_input_vec {
+            if expense1 + expense2 == 2020 {
+                product = expense1 * expense2;
+                break 'outer;
+            }
+        }
+    }
+
+    product
+}
+
+fn day1_part2(day1_input_vec: &[i32]) -> i32 {
+    let mut product = 0;
+
+    // Borrow the day1_input_vec (with &) so as to not consume it and allow us to iterate on it on
+    // the inner loop as well
+    'part2_outer: for expense1 in day1_input_vec {
+        for expense2 in day1_input_vec {
+            let expense3_expected = 2020 - (expense1 + expense2);
+
+            if day1_input_vec.contains(&expense3_expected) {
+                product = expense1 * expense2 * expense3_expected;
+                break 'part2_outer;
+            }
+        }
+    }
+
+    product
+}
+
+/// Splits the input into a Vector of Strings for each line
+fn read_lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+fn day2_part1(day2_input_vec: &[String]) -> i32 {
+    struct PasswordRule {
+        minimum: i32,
+        maximum: i32,
+        value: char,
+    }
+
+    let mut valid_count = 0;
+    for line in day2_input_vec {
+        // Assumed input values! No error checking
+        // Input example: 1-3 a: abcdef
+        let password_tokens: Vec<&str> = line.split(':').collect();
+        let rule_tokens: Vec<&str> = password_tokens[0].split(' ').collect();
+        let min_max_tokens: Vec<&str> = rule_tokens[0].split('-').collect();
+
+        let rule: PasswordRule = PasswordRule {
+            minimum: min_max_tokens[0].parse().unwrap(),
+            maximum: min_max_tokens[1].parse().unwrap(),
+
+            // Assume rule_token[1] contains the character to check and convert it to a char
+            value: rule_tokens[1].chars().next().unwrap(),
+        };
+
+        // Use filter to count the frequency of the given character
+        let frequency: i32 = password_tokens[1].chars()
+            .filter(|letter| *letter == rule.value).count() as i32;
+
+        if frequency >= rule.minimum && frequency <= rule.maximum {
+            valid_count += 1;
+        }
+    }
+
+    valid_count
+}
+
+fn day2_part2(day2_input_vec: &[String]) -> i32 {
+    struct PasswordRule {
+        position1: usize,
+        position2: usize,
+        value: char,
+    }
+
+    let mut valid_count = 0;
+    for line in day2_input_vec {
+        // Assumed input values! No error checking
+        // Input example: 1-3 a: abcdef
+        let password_tokens: Vec<&str> = line.split(':').collect();
+        let rule_tokens: Vec<&str> = password_tokens[0].split(' ').collect();
+        let position_tokens: Vec<&str> = rule_tokens[0].split('-').collect();
+
+        let rule: PasswordRule = PasswordRule {
+            position1: position_tokens[0].parse::<usize>().unwrap(),
+            position2: position_tokens[1].parse::<usize>().unwrap(),
+
+            // Assume rule_token[1] contains the character to check and convert it to a char
+            value: rule_tokens[1].chars().next().unwrap(),
+        };
+
+        // Do an XOR check to count how many passwords are valid
+        let password = password_tokens[1];
+        if (password.chars().nth(rule.position1).unwrap() == rule.value) !=
+            (password.chars().nth(rule.position2).unwrap() == rule.value) {
+            valid_count += 1;
+        }
+    }
+
+    valid_count
+}
+
+fn day3_parse(input: &str) -> Grid<char> {
+    parse::<Grid<char>>(input)
+}
+
+/// Counts the trees hit on the way down from the top left, moving right and down by the given amounts each step. The
+/// landscape repeats to the right as far as it needs to.
+fn day3_count_trees(landscape: &Grid<char>, right_movement: i64, down_movement: i64) -> usize {
+    const TREE: char = '#';
+
+    WrappingGrid::new(landscape, Wrap::Horizontal)
+        .count_on_slope(Point::ORIGIN, Point::new(right_movement, down_movement), &TREE)
+}
+
+fn day3_part1(landscape: &Grid<char>) -> usize {
+    day3_count_trees(landscape, 3, 1)
+}
+
+fn day3_part2(landscape: &Grid<char>) -> usize {
+    let movements: Vec<(i64, i64)> = vec![
+        (1, 1),
+        (3, 1),
+        (5, 1),
+        (7, 1),
+        (1, 2),
+    ];
+
+    movements.into_iter()
+        .map(|(right_movement, down_movement)| day3_count_trees(landscape, right_movement, down_movement))
+        .product()
+}
+
+fn day4_part1_is_valid_passport(passport: &[String]) -> bool {
+    let number_of_required_fields: usize = 7;
+
+    let mut tags: HashMap<&str, bool>= HashMap::new();
+    for pair in passport {
+        let key_value_vec: Vec<&str> = pair.split(':').collect();
+        tags.entry(key_value_vec[0]).or_insert(true);
+    }
+
+    // Valid passports may or may not have the "cid" field, so we don't care if it exists
+    // Since all the other fields are required, all 7 of them must exist
+    number_of_required_fields == tags.iter().filter(|&(key, value)| *key != "cid" && *value).count()
+}
+
+fn is_valid_height(value: &str) -> bool {
+    // unit check
+    if value.len() >= 2 {
+        if value.ends_with("in") {
+            let height: i32 = value.trim_end_matches("in").parse().unwrap();
+            (59..=76).contains(&height)
+        } else if value.ends_with("cm") {
+            let height: i32 = value.trim_end_matches("cm").parse().unwrap();
+            (150..=193).contains(&height)
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
+
+fn is_valid_hair_color(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^#[0-9a-f]{6}$").unwrap();
+    }
+
+    RE.is_match(value)
+}
+
+fn is_valid_eye_color(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^(amb)|(blu)|(brn)|(gry)|(grn)|(hzl)|(oth)$").unwrap();
+    }
+
+    RE.is_match(value)
+}
+
+fn day4_part2_is_field_valid(field_name: &str, value: &str) -> bool {
+    // byr (Birth Year)
+    // iyr (Issue Year)
+    // eyr (Expiration Year)
+    // hgt (Height)
+    // hcl (Hair Color)
+    // ecl (Eye Color)
+    // pid (Passport ID)
+    // cid (Country ID)
+    match field_name {
+        "byr" => value.parse::<i32>().unwrap() >= 1920 && value.parse::<i32>().unwrap() <= 2002,
+        "iyr" => value.parse::<i32>().unwrap() >= 2010 && value.parse::<i32>().unwrap() <= 2020,
+        "eyr" => value.parse::<i32>().unwrap() >= 2020 && value.parse::<i32>().unwrap() <= 2030,
+        "hgt" => is_valid_height(value),
+        "hcl" => is_valid_hair_color(value),
+        "ecl" => is_valid_eye_color(value),
+        "pid" => value.chars().filter(|the_char| the_char.is_ascii_digit()).count() == 9,
+
+        // The cid field is optional so ignore it
+        //"cid" => true,
+
+        // No other values are valid
+        _ => false,
+    }
+}
+
+fn day4_part2_is_valid_passport(passport: &[String]) -> bool {
+    let number_of_required_fields: usize = 7;
+
+    let mut tags: HashMap<&str, bool>= HashMap::new();
+    for pair in passport {
+        let key_value_vec: Vec<&str> = pair.split(':').collect();
+        let key = key_value_vec[0];
+        let value = key_value_vec[1];
+
+        if day4_part2_is_field_valid(key, value) {
+            tags.entry(key).or_insert(true);
+        }
+    }
+
+    // Valid passports may or may not have the "cid" field, so we don't care if it exists
+    // Since all the other fields are required, all 7 of them must exist
+    number_of_required_fields == tags.iter().filter(|&(key, value)| *key != "cid" && *value).count()
+}
+
+fn day4_parse(input: &str) -> Vec<Vec<String>> {
+    // byr (Birth Year)
+    // iyr (Issue Year)
+    // eyr (Expiration Year)
+    // hgt (Height)
+    // hcl (Hair Color)
+    // ecl (Eye Color)
+    // pid (Passport ID)
+    // cid (Country ID)
+
+    // Passports are separated by blank lines, and each one's fields can be spread over several lines
+    records(input).iter()
+        .map(|passport| passport.lines.iter().flat_map(|line| line.split_whitespace()).map(String::from).collect())
+        .collect()
+}
+
+fn day4_part1(passport_list: &[Vec<String>]) -> usize {
+    let mut valid_passport_count_part1 = 0;
+    for passport in passport_list {
+        if day4_part1_is_valid_passport(passport) {
+            valid_passport_count_part1 += 1;
+        }
+    }
+
+    valid_passport_count_part1
+}
+
+fn day4_part2(passport_list: &[Vec<String>]) -> usize {
+    let mut valid_passport_count_part2 = 0;
+    for passport in passport_list {
+        if day4_part2_is_valid_passport(passport) {
+            valid_passport_count_part2 += 1;
+        }
+    }
+
+    valid_passport_count_part2
+}
+
+fn day5_parse(input: &str) -> Vec<i32> {
+    let mut assigned_seat_list: Vec<i32> = vec![];
+
+    // Each seat ID in the list can be represented as a 10 bit value where:
+    // F and L are 0
+    // B and R are 1
+    // For example, the seat FBFBFFBRRR is 0101001111 which is 335
+
+    for seat in input.lines() {
+        let mut seat_bits: u16 = 0;
+        for designation in seat.chars() {
+            seat_bits <<= 1;
+            if designation == 'B' || designation == 'R' {
+                seat_bits |= 1;
+            }
+        }
+
+        assigned_seat_list.push(seat_bits as i32);
+    }
+
+    assigned_seat_list
+}
+
+fn day5_part1(assigned_seat_list: &[i32]) -> i32 {
+    let max_seat_id: i32 = *assigned_seat_list.iter().max().unwrap();
+
+    max_seat_id
+}
+
+fn day5_part2(assigned_seat_list: &[i32]) -> i32 {
+    let min_seat_id: i32 = *assigned_seat_list.iter().min().unwrap();
+    let max_seat_id: i32 = *assigned_seat_list.iter().max().unwrap();
+
+    let mut my_seat_id: i32 = 0;
+
+    // Binary search through the assigned seats to find the missing one
+    let mut assigned_seat_list = assigned_seat_list.to_vec();
+    assigned_seat_list.sort();
+    for seat_id_check in min_seat_id..max_seat_id {
+        let result = assigned_seat_list.binary_search(&seat_id_check);
+        if result.is_err() {
+            // This is my seat!
+            my_seat_id = seat_id_check;
+            break;
+        }
+    }
+
+    my_seat_id
+}
+
+/// Each group's answers, with one String per person in the group
+fn day6_parse(input: &str) -> Vec<Vec<String>> {
+    records(input).iter()
+        .map(|group| group.lines.iter().map(|person| person.trim().to_string()).collect())
+        .collect()
+}
+
+fn day6_part1(all_group_answers: &[Vec<String>]) -> usize {
+    let mut answer_sum = 0;
+    for group_answers in all_group_answers {
+        let group_answer: HashSet<char> = group_answers.iter().flat_map(|person| person.chars()).collect();
+        answer_sum += group_answer.len();
+    }
+
+    answer_sum
+}
+
+fn day6_part2(all_group_answers: &[Vec<String>]) -> usize {
+    let possible_answers = "abcdefghijklmnopqrstuvwxyz";
+
+    let mut answer_sum: usize = 0;
+    for group_answers in all_group_answers {
+
+        // Iterate over all the possible answers and count how many times they show up in the
+        // group's answers. If they show up the same number of times as the number of people in
+        // the group, that counts toward the sum.
+        let result = possible_answers.chars()
+            .map(|character| (character, group_answers.iter().filter(|person| person.contains(character)).count()))
+            .collect::<std::collections::HashMap<_, _>>();
+
+        let number_of_people = group_answers.len();
+
+        answer_sum += result.values()
+            .filter(|&value| *value == number_of_people)
+            .count();
+    }
+
+    answer_sum
+}
+
+fn count_bags_in_bag(total_list: &HashMap<String, HashMap<String, usize>>, bag_node_list: &HashMap<String, usize>) -> usize {
+    let mut bag_sum: usize = 0;
+    for bag_node in bag_node_list {
+        bag_sum += bag_node.1;
+        bag_sum += bag_node.1 * count_bags_in_bag(total_list, total_list.get(bag_node.0).unwrap());
+    }
+
+    bag_sum
+}
+
+/// Each bag type mapped to the bag types (and how many of them) it must contain
+type Bag = HashMap<String, HashMap<String, usize>>;
+
+fn day7_parse(input: &str) -> Bag {
+    let mut parsed_bag_rule_list: Bag = HashMap::new();
+
+    for bag_rule in input.lines() {
+        let mut rule_iter = bag_rule.split(" bags contain ");
+        let bag_type: String = rule_iter.next().unwrap().to_string();
+
+        lazy_static! {
+            static ref RE: Regex = Regex::new(" bag[s]?[,.][ ]?").expect("");
+        }
+        let rules: Vec<&str> = RE.split(rule_iter.next().unwrap()).collect();
+
+        let ruleset: HashMap<String, usize> = rules.iter()
+            .filter(|&rule| !rule.is_empty() && *rule != "no other")
+            .map(|&rule| {
+                let count_str = rule.split(' ').next().unwrap();
+                let count = count_str.parse::<usize>().unwrap_or_default();
+
+                // Use the count to construct a delimiter for splitting the bag type from the count
+                let count_split_pattern = format!("{} ", count_str);
+                let bag = rule.split(&count_split_pattern).nth(1).unwrap_or_default();
+
+                (bag.to_string(), count)
+            })
+            .collect::<HashMap<_, _>>();
+
+        parsed_bag_rule_list.insert(bag_type, ruleset);
+    }
+
+    parsed_bag_rule_list
+}
+
+fn day7_part1(parsed_bag_rule_list: &Bag) -> usize {
+    const SHINY_GOLD: &str = "shiny gold";
+
+    // Search from each of the other bags through the bags it holds to see if a shiny gold bag is in there somewhere
+    let bag_count = parsed_bag_rule_list.keys()
+        .filter(|&bag| bag != SHINY_GOLD)
+        .filter(|&bag| {
+            bfs(bag.as_str(),
+                |inner_bag| parsed_bag_rule_list[*inner_bag].keys().map(String::as_str),
+                |inner_bag| *inner_bag == SHINY_GOLD).is_some()
+        })
+        .count();
+
+    bag_count
+}
+
+fn day7_part2(parsed_bag_rule_list: &Bag) -> usize {
+    count_bags_in_bag(parsed_bag_rule_list, parsed_bag_rule_list.get("shiny gold").unwrap())
+}
+
+fn test_bootcode(bootcode: &[String]) -> (bool, i32) {
+    let mut accumlator = 0;
+    let mut instruction_pointer: i32 = 0;
+    let mut instruction_tracker: HashSet<i32> = HashSet::new();
+
+    while instruction_pointer < bootcode.len() as i32 {
+        let instruction = bootcode.get(instruction_pointer as usize).unwrap();
+        if !instruction_tracker.insert(instruction_pointer) {
+            break;
+        }
+        match instruction.split(' ').next().unwrap() {
+            "acc" => {
+                accumlator += instruction.split(' ').nth(1).unwrap().parse::<i32>().unwrap();
+                instruction_pointer += 1;
+            },
+            "jmp" => {
+                instruction_pointer += instruction.split(' ').nth(1).unwrap().parse::<i32>().unwrap();
+            }
+            _ => instruction_pointer += 1,
+        }
+    }
+
+    if instruction_pointer >= bootcode.len() as i32 {
+        (true, accumlator)
+    } else {
+        (false, accumlator)
+    }
+}
+
+fn day8_part1(bootcode: &[String]) -> i32 {
+    let result = test_bootcode(bootcode);
+
+    result.1
+}
+
+fn day8_part2(bootcode: &[String]) -> i32 {
+    let mut accumulator = 0;
+
+    for (ii, instruction) in bootcode.iter().enumerate() {
+        // @TODO: Is there a better way to swap these instructions?
+        let new_instruction: &str = match instruction.split(' ').next().unwrap() {
+            "nop" => "jmp",
+            "jmp" => "nop",
+
+            // For all other cases, skip this iteration since it doesn't change the behavior of
+            // the bootcode
+            _ => continue,
+        };
+
+        // Try swapping the nop/jmp command and running the code. If it completes successfully,
+        // then that's the value we're looking for.
+        let mut bootcode_to_test = bootcode.to_vec();
+        bootcode_to_test[ii] = format!("{} {}", new_instruction, instruction.split(' ').nth(1).unwrap());
+
+        let result = test_bootcode(&bootcode_to_test);
+        if result.0 {
+            accumulator = result.1;
+        }
+    }
+
+    accumulator
+}
+
+fn find_two_values_for_sum(slice: &[u64], sum: u64) -> bool {
+    let mut result = false;
+    'outer: for value1 in slice {
+        for value2 in slice {
+            if value1 + value2 == sum {
+                //println!("value check: {} | {}", expense1, expense2);
+                result = true;
+                break 'outer
+            }
+        }
+    }
+
+    result
+}
+
+fn day9_parse(input: &str) -> Vec<u64> {
+    parse::<Lines<u64>>(input).0
+}
+
+/// Finds the first value in the series that is not the sum of two of the values in the preamble before it
+fn find_rule_breaker(xmas_series: &[u64]) -> u64 {
+    const PREAMBLE_LENGTH: usize = 25;
+
+    let mut rule_breaker: u64 = 0;
+    for (index, value) in xmas_series.iter().enumerate() {
+        if index < PREAMBLE_LENGTH {
+            continue
+        }
+
+        let slice = &xmas_series[index - PREAMBLE_LENGTH..index];
+
+        if !find_two_values_for_sum(slice, *value) {
+            rule_breaker = *value;
+            break;
+        }
+    }
+
+    rule_breaker
+}
+
+fn day9_part1(xmas_series: &[u64]) -> u64 {
+    find_rule_breaker(xmas_series)
+}
+
+fn day9_part2(xmas_series: &[u64]) -> u64 {
+    let rule_breaker = find_rule_breaker(xmas_series);
+    let mut weakness: u64 = 0;
+
+    let mut start_index = 0;
+    let mut end_index = 2;
+
+    while end_index < xmas_series.len() {
+        let slice: &[u64] = &xmas_series[start_index..end_index];
+        let sum: u64 = slice.iter().sum();
+        //println!("{:?} | sum: {}", slice, sum);
+
+        if sum > rule_breaker {
+            start_index += 1;
+            end_index = start_index + 2;
+        } else if sum == rule_breaker {
+            let min = slice.iter().min().unwrap();
+            let max = slice.iter().max().unwrap();
+
+            weakness = min + max;
+            break;
+        } else {
+            end_index += 1;
+        }
+    }
+
+    weakness
+}
+
+fn day10_parse(input: &str) -> Vec<u32> {
+    let Lines(mut adapter_list) = parse::<Lines<u32>>(input);
+
+    // 0 is not included in the input data
+    adapter_list.push(0);
+    adapter_list.sort();
+
+    // The final value is always 3 more than the highest value in the vector
+    adapter_list.push(adapter_list.last().unwrap() + 3);
+
+    adapter_list
+}
+
+/// The joltage difference between each adapter and the next one in the chain
+fn adapter_differences(adapter_list: &[u32]) -> Vec<u32> {
+    adapter_list.windows(2)
+        .map(|window| window[1]- window[0])
+        .collect()
+}
+
+fn day10_part1(adapter_list: &[u32]) -> usize {
+    let differences: Vec<u32> = adapter_differences(adapter_list);
+    let count_of_ones = differences.iter()
+        .filter(|&value| *value == 1)
+        .count();
+    let count_of_threes = differences.iter()
+        .filter(|&value| *value == 3)
+        .count();
+
+    count_of_ones * count_of_threes
+}
+
+fn day10_part2(adapter_list: &[u32]) -> u128 {
+    let differences: Vec<u32> = adapter_differences(adapter_list);
+
+    let mut sequence_tracker: HashMap<u32, u32> = HashMap::new();
+    let mut sequence_count: u32 = 0;
+    for diff in differences.iter() {
+        match *diff {
+            1 => sequence_count += 1,
+            _ => {
+                // Update or insert the number of times this sequence has been seen
+                (*sequence_tracker.entry(sequence_count).or_insert(0)) += 1;
+                sequence_count = 0;
+            }
+        }
+    }
+
+    // The number of permutations is:
+    // 1^(sequences of 0) * 1^(sequences of 2) * 2^(sequences of 3) * 4^(sequences of 4) * 7^(sequences of 5) * 13^(sequences of 6)
+    //
+    // The first 2 items can be ignored in the math since they always work out to 1; however, it
+    // makes the fold operation a bit easier for grabbing the index to leave them in.
+    let pattern: Vec<u32> = vec![1, 1, 2, 4, 7, 13];
+    let permutations: u128 = pattern.iter()
+        .enumerate()
+        .fold(1, |product, (index, value)| {
+            product * (*value).pow(*sequence_tracker.get(&(index as u32)).unwrap_or(&0)) as u128
+        });
+
+    permutations
+}
+
+fn day11_parse(input: &str) -> Grid<char> {
+    parse::<Grid<char>>(input)
+}
+
+/// Keeps applying the seating rules until nobody moves, showing the callback every generation along the way. An empty
+/// seat fills up when none of the seats around it are taken, and a taken seat empties when at least `crowding` of them
+/// are, with the neighbourhood deciding which seats count as around it.
+fn settle(input_seat_list: &Grid<char>, crowding: usize, neighbourhood: Neighbourhood<char>,
+          mut on_generation: impl FnMut(&Grid<char>)) -> Grid<char> {
+    let rule = |seat: &char, around: Neighbours<'_, char>| match seat {
+        'L' if around.count(&'#') == 0 => '#',
+        '#' if around.count(&'#') >= crowding => 'L',
+        _ => *seat,
+    };
+    let mut seating = Automaton::new(input_seat_list.clone(), neighbourhood, rule);
+
+    on_generation(seating.grid());
+    while seating.step() {
+        on_generation(seating.grid());
+    }
+
+    seating.into_grid()
+}
+
+/// Part 1 only looks at the eight adjacent cells, while part 2 looks past the floor to the first seat in each direction
+fn seating_neighbourhood(part: u8) -> Neighbourhood<char> {
+    match part {
+        1 => Neighbourhood::Moore,
+        _ => Neighbourhood::LineOfSight(|cell| *cell == '.'),
+    }
+}
+
+fn day11_part1(input_seat_list: &Grid<char>) -> usize {
+    settle(input_seat_list, 4, seating_neighbourhood(1), |_| {}).count(&'#')
+}
+
+fn day11_part2(input_seat_list: &Grid<char>) -> usize {
+    settle(input_seat_list, 5, seating_neighbourhood(2), |_| {}).count(&'#')
+}
+
+/// Each generation of the seating until it settles, using the given part's rules
+fn day11_frames(input: &str, part: u8, frame: &mut dyn FnMut(&Grid<char>)) {
+    let crowding = if part == 1 { 4 } else { 5 };
+    settle(&day11_parse(input), crowding, seating_neighbourhood(part), frame);
+}
+
+#[derive(Copy, Clone)]
+enum CardinalDirection {
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
+}
+
+impl Add<i32> for CardinalDirection {
+    type Output = Self;
+
+    fn add(self, other_degrees: i32) -> CardinalDirection {
+        let ticks = other_degrees / 90;
+
+        let mut new_value = self as i32 + ticks;
+        while new_value > 4 {
+            new_value -= 4;
+        }
+
+        // @TODO: There must be a better way in Rust to translate these values back to the enum value
+        match new_value {
+            0 => CardinalDirection::North,
+            1 => CardinalDirection::East,
+            2 => CardinalDirection::South,
+            3 => CardinalDirection::West,
+            _ => CardinalDirection::North,
+        }
+    }
+}
+
+impl Sub<i32> for CardinalDirection {
+    type Output = Self;
+
+    fn sub(self, other_degrees: i32) -> CardinalDirection {
+        let ticks = other_degrees / 90;
+
+        let mut new_value = self as i32 - ticks;
+        while new_value < 0 {
+            new_value += 4;
+        }
+
+        // @TODO: There must be a better way in Rust to translate these values back to the enum value
+        match new_value {
+            0 => CardinalDirection::North,
+            1 => CardinalDirection::East,
+            2 => CardinalDirection::South,
+            3 => CardinalDirection::West,
+            _ => CardinalDirection::North,
+        }
+    }
+}
+
+impl AddAssign<i32> for CardinalDirection {
+    fn add_assign(&mut self, other_degrees: i32) {
+        *self = self.add(other_degrees);
+    }
+}
+
+impl SubAssign<i32> for CardinalDirection {
+    fn sub_assign(&mut self, other_degrees: i32) {
+        *self = self.sub(other_degrees);
+    }
+}
+
+impl Display for CardinalDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self {
+            CardinalDirection::North => "North",
+            CardinalDirection::East => "East",
+            CardinalDirection::South => "South",
+            CardinalDirection::West => "West",
+        };
+        write!(f, "{}", direction)
+    }
+}
+
+fn day12_part1(navigation_instructions: &[String]) -> i32 {
+    let mut current_heading: CardinalDirection = CardinalDirection::East;
+    let mut ship = Point::ORIGIN;
+
+    for instruction in navigation_instructions {
+        let dir_instruction = instruction.chars().next().unwrap();
+        let count = instruction.split(&dir_instruction.to_string()).nth(1).unwrap().parse::<i64>().unwrap();
+
+        match dir_instruction {
+            'N' => ship += Direction::North.offset() * count,
+            'S' => ship += Direction::South.offset() * count,
+            'E' => ship += Direction::East.offset() * count,
+            'W' => ship += Direction::West.offset() * count,
+            'L' => {
+                current_heading -= count as i32;
+            },
+            'R' => {
+                current_heading += count as i32;
+            },
+            'F' => {
+                let heading = match current_heading {
+                    CardinalDirection::North => Direction::North,
+                    CardinalDirection::South => Direction::South,
+                    CardinalDirection::East => Direction::East,
+                    CardinalDirection::West => Direction::West,
+                };
+                ship += heading.offset() * count;
+            },
+            _ => {},
+        }
+    }
+
+    ship.manhattan_distance(Point::ORIGIN) as i32
+}
+
+fn day12_part2(navigation_instructions: &[String]) -> i32 {
+    let mut ship = Point::ORIGIN;
+    // 10 units east and 1 unit north of the ship, remembering that y counts down
+    let mut way_point = Point::new(10, -1);
+
+    for instruction in navigation_instructions {
+        let dir_instruction = instruction.chars().next().unwrap();
+        let count = instruction.split(&dir_instruction.to_string()).nth(1).unwrap().parse::<i64>().unwrap();
+
+        match dir_instruction {
+            'N' => way_point += Direction::North.offset() * count,
+            'S' => way_point += Direction::South.offset() * count,
+            'E' => way_point += Direction::East.offset() * count,
+            'W' => way_point += Direction::West.offset() * count,
+            'L' => {
+                let ticks = count / 90;
+                match ticks {
+                    1 => way_point = Point::new(way_point.y, -way_point.x),
+                    2 => way_point = Point::new(-way_point.x, -way_point.y),
+                    3 => way_point = Point::new(-way_point.y, way_point.x),
+                    _ => {}
+                }
+            },
+            'R' => {
+                let ticks = count / 90;
+                match ticks {
+                    1 => way_point = Point::new(-way_point.y, way_point.x),
+                    2 => way_point = Point::new(-way_point.x, -way_point.y),
+                    3 => way_point = Point::new(way_point.y, -way_point.x),
+                    _ => {}
+                }
+            },
+            'F' => {
+                ship += way_point * count;
+            },
+            _ => {},
+        }
+    }
+
+    ship.manhattan_distance(Point::ORIGIN) as i32
+}
+
+fn day13_part1(shuttle_notes: &[String]) -> u32 {
+    let earliest_time = shuttle_notes[0].parse::<u32>().unwrap();
+    let buses: Vec<u32> = shuttle_notes[1].split(',')
+        .filter(|&bus| bus != "x")
+        .map(|bus| bus.parse::<u32>().unwrap())
+        .collect();
+
+    let mut earliest_bus: u32 = 0;
+    let mut departure_time: u32 = 0;
+    'outer: for time in earliest_time..=(earliest_time + buses.iter().max().unwrap()) {
+        for bus in &buses {
+            if time % bus == 0 {
+                earliest_bus = *bus;
+                departure_time = time;
+                break 'outer;
+            }
+        }
+    }
+
+    earliest_bus * (departure_time - earliest_time)
+}
+
+#[cfg(test)]
+mod day4_tests {
+    use crate::{day4_parse, day4_part1};
+
+    #[test]
+    fn test_day4_keeps_last_passport() {
+        let passports = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\nbyr:1937 iyr:2017 cid:147 hgt:183cm\n\n\
+                         hcl:#ae17e1 iyr:2013 eyr:2024 ecl:brn pid:760753108 byr:1931 hgt:179cm";
+
+        assert_eq!(day4_part1(&day4_parse(passports)), 2);
+        assert_eq!(day4_part1(&day4_parse(&format!("{}\n", passports))), 2);
+        assert_eq!(day4_part1(&day4_parse(&format!("{}\n\n\n", passports))), 2);
+        assert_eq!(day4_part1(&day4_parse(&passports.replace('\n', "\r\n"))), 2);
+    }
+}
+
+#[cfg(test)]
+mod day6_tests {
+    use crate::{day6_parse, day6_part1, day6_part2};
+
+    #[test]
+    fn test_day6_line_endings() {
+        let answers = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb";
+
+        for input in [answers.to_string(), format!("{}\n", answers), format!("{}\n\n\n", answers),
+                      answers.replace('\n', "\r\n"), format!("{}\r\n", answers.replace('\n', " \r\n"))] {
+            let groups = day6_parse(&input);
+            assert_eq!(day6_part1(&groups), 11, "{:?}", input);
+            assert_eq!(day6_part2(&groups), 6, "{:?}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod example_tests {
+    aoc_common::example_tests!(crate::year(),
+        day1_example1,
+        day2_example1,
+        day3_example1,
+        day4_example1,
+        day5_example1,
+        day6_example1,
+        day7_example1,
+        day7_example2,
+        day8_example1,
+        day10_example1,
+        day10_example2,
+        day11_example1,
+        day12_example1,
+        day13_example1,
+    );
+}
@@ -1,16 +1,28 @@
 use std::cmp;
-use std::fs::File;
-use std::io::{self, BufRead};
 
-fn _day1_part1() {
-    println!("--- Day 1: Trebuchet ---");
-    println!("--- Part 1           ---\n");
+use aoc_common::grid::Grid;
+use aoc_common::parse::parse;
+use aoc_common::spans::{number_spans, NumberSpan};
+use aoc_common::{puzzle, Year};
+
+/// All of the 2023 puzzles that have been solved so far
+pub fn year() -> Year {
+    #[cfg(feature = "embed")]
+    aoc_common::input::embed(include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs")));
+
+    Year::new(2023, env!("CARGO_MANIFEST_DIR"), vec![
+        puzzle!(1, "Trebuchet", read_lines, day1_part1, day1_part2),
+        puzzle!(2, "Cube Conundrum", read_lines, day2_part1, day2_part2),
+        puzzle!(3, "Gear Ratios", day3_parse, day3_part1, day3_part2),
+    ])
+}
 
-    let input_path = "input_data/day1_input.txt";
+/// Splits the input into a Vector of Strings for each line
+fn read_lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
 
-    let file = File::open(input_path);
-    let reader = io::BufReader::new(file.unwrap());
-    let day1_input: Vec<String> = reader.lines().flatten().flat_map(|l| l.parse()).collect();
+fn day1_part1(day1_input: &[String]) -> i32 {
 
     let mut calibration_total: i32 = 0;
     for artsy_calibration in day1_input {
@@ -18,7 +30,7 @@ fn _day1_part1() {
         let mut last_digit = ' ';
         for calibration_char in artsy_calibration.chars() {
             if calibration_char.is_numeric() {
-                if false == first_digit.is_numeric() {
+                if !first_digit.is_numeric() {
                     // If the first_digit has not been assigned yet, this must be the first digit
                     first_digit = calibration_char;
                 }
@@ -33,44 +45,36 @@ fn _day1_part1() {
         calibration_total += calibration.parse::<i32>().unwrap();
     }
 
-    println!(">>>> calibration sum:{calibration_total}");
+    calibration_total
 }
 
 /// Attempts to convert the given string into a numeric character. Returns a tuple with a boolean indicating success
 /// or failure and the numeric character.
-fn _convert_word_to_numeric(word: &str) -> (bool, char) {
+fn convert_word_to_numeric(word: &str) -> (bool, char) {
     if word.ends_with("one") {
-        (true, '1' as char)
+        (true, '1')
     } else if word.ends_with("two") {
-        (true, '2' as char)
+        (true, '2')
     } else if word.ends_with("three") {
-        (true, '3' as char)
+        (true, '3')
     } else if word.ends_with("four") {
-        (true, '4' as char)
+        (true, '4')
     } else if word.ends_with("five") {
-        (true, '5' as char)
+        (true, '5')
     } else if word.ends_with("six") {
-        (true, '6' as char)
+        (true, '6')
     } else if word.ends_with("seven") {
-        (true, '7' as char)
+        (true, '7')
     } else if word.ends_with("eight") {
-        (true, '8' as char)
+        (true, '8')
     } else if word.ends_with("nine") {
-        (true, '9' as char)
+        (true, '9')
     } else {
-        (false, '0' as char)
+        (false, '0')
     }
 }
 
-fn _day1_part2() {
-    println!("--- Day 1: Trebuchet ---");
-    println!("--- Part 2           ---\n");
-
-    let input_path = "input_data/day1_input.txt";
-
-    let file = File::open(input_path);
-    let reader = io::BufReader::new(file.unwrap());
-    let day1_input: Vec<String> = reader.lines().flatten().flat_map(|l| l.parse()).collect();
+fn day1_part2(day1_input: &[String]) -> i32 {
 
     let mut calibration_total: i32 = 0;
     for artsy_calibration in day1_input {
@@ -79,7 +83,7 @@ fn _day1_part2() {
         let mut digit_buffer: String = Default::default();
         for calibration_char in artsy_calibration.chars() {
             if calibration_char.is_numeric() {
-                if false == first_digit.is_numeric() {
+                if !first_digit.is_numeric() {
                     // If the first_digit has not been assigned yet, this must be the first digit
                     first_digit = calibration_char;
                 }
@@ -92,10 +96,10 @@ fn _day1_part2() {
             } else {
                 digit_buffer.push(calibration_char);
 
-                let converted = _convert_word_to_numeric(&digit_buffer);
+                let converted = convert_word_to_numeric(&digit_buffer);
                 // .0 contains whether or not the conversion was successful
                 if converted.0 {
-                    if false == first_digit.is_numeric() {
+                    if !first_digit.is_numeric() {
                         first_digit = converted.1
                     }
 
@@ -112,18 +116,10 @@ fn _day1_part2() {
         calibration_total += calibration.parse::<i32>().unwrap();
     }
 
-    println!(">>>> calibration sum:{calibration_total}");
+    calibration_total
 }
 
-fn _day2_part1() {
-    println!("--- Day 2: Cube Conundrum ---");
-    println!("--- Part 1                ---\n");
-
-    let input_path = "input_data/day2_input.txt";
-
-    let file = File::open(input_path);
-    let reader = io::BufReader::new(file.unwrap());
-    let day2_input: Vec<String> = reader.lines().flatten().flat_map(|l| l.parse()).collect();
+fn day2_part1(day2_input: &[String]) -> i32 {
 
     let max_red_cubes: i32 = 12;
     let max_green_cubes: i32 = 13;
@@ -163,23 +159,15 @@ fn _day2_part1() {
             }
         }
 
-        if false == impossible_hand {
+        if !impossible_hand {
             count += game_index;
         }
     }
 
-    println!(">>>> sum: {count}");
+    count
 }
 
-fn _day2_part2() {
-    println!("--- Day 2: Cube Conundrum ---");
-    println!("--- Part 2                ---\n");
-
-    let input_path = "input_data/day2_input.txt";
-
-    let file = File::open(input_path);
-    let reader = io::BufReader::new(file.unwrap());
-    let day2_input: Vec<String> = reader.lines().flatten().flat_map(|l| l.parse()).collect();
+fn day2_part2(day2_input: &[String]) -> i32 {
 
     let mut count: i32 = 0;
 
@@ -214,59 +202,61 @@ fn _day2_part2() {
         count += local_red_max * local_green_max * local_blue_max;
     }
 
-    println!(">>>> sum: {count}");
+    count
 }
 
-fn _day3_part1() {
-    println!("--- Day 3: Gear Ratios ---");
-    println!("--- Part 1             ---\n");
+/// The engine schematic, along with every number written on it
+struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<NumberSpan>,
+}
 
-    let input_path = "input_data/day3_input.txt";
+fn day3_parse(input: &str) -> Schematic {
+    let grid: Grid<char> = parse::<Grid<char>>(input);
+    let numbers = number_spans(&grid);
 
-    let file = File::open(input_path);
-    let reader = io::BufReader::new(file.unwrap());
-    let day3_input: Vec<String> = reader.lines().flatten().flat_map(|l| l.parse()).collect();
+    Schematic { grid, numbers }
+}
 
-    let mut schematic: Vec<Vec<char>> = vec![];
+/// Anything other than a digit or a blank period is a symbol
+fn is_symbol(cell: char) -> bool {
+    cell != '.' && !cell.is_ascii_digit()
+}
 
-    for line in day3_input {
-        let schematic_line: Vec<char> = line.chars().collect::<Vec<char>>();
-        schematic.push(schematic_line);
-    }
+fn day3_part1(schematic: &Schematic) -> u64 {
+    // Part numbers are the numbers next to a symbol, even diagonally
+    schematic.numbers.iter()
+        .filter(|number| !number.symbols(&schematic.grid, is_symbol).is_empty())
+        .map(|number| number.value)
+        .sum()
+}
 
-    let mut line_index = 0;
+fn day3_part2(schematic: &Schematic) -> u64 {
+    const GEAR: char = '*';
 
-    for line in schematic {
-        line_index += 1;
+    let mut gear_ratio_total = 0;
 
-        let mut col_index = 0;
-        for digit in line {
-            col_index += 1;
-            print!("{digit}");
-        }
+    for gear in schematic.grid.find_all(&GEAR) {
+        // A * is only a gear when it's next to exactly two part numbers, and its ratio is their product
+        let part_numbers: Vec<u64> = schematic.numbers.iter()
+            .filter(|number| number.touches(gear))
+            .map(|number| number.value)
+            .collect();
 
-        print!("\n");
+        if let [first, second] = part_numbers[..] {
+            gear_ratio_total += first * second;
+        }
     }
+
+    gear_ratio_total
 }
 
-fn main() {
-    println!("         .     .  .      +     .      .          .");
-    println!("     .       .      .     #       .           .");
-    println!("        .      .         ###            .      .      .");
-    println!("      .      .   \"#:. .:##\"##:. .:#\"  .      .");
-    println!("          .      . \"####\"###\"####\"  .");
-    println!("       .     \"#:.    .:#\"###\"#:.    .:#\"  .        .       .");
-    println!("  .             \"#########\"#########\"        .        .");
-    println!("        .    \"#:.  \"####\"###\"####\"  .:#\"   .       .");
-    println!("     .     .  \"#######\"\"##\"##\"\"#######\"                  .");
-    println!("                .\"##\"#####\"#####\"##\"           .      .");
-    println!("    .   \"#:. ...  .:##\"###\"###\"##:.  ... .:#\"     .");
-    println!("      .     \"#######\"##\"#####\"##\"#######\"      .     .");
-    println!("    .    .     \"#####\"\"#######\"\"#####\"    .      .");
-    println!("            .     \"      000      \"    .     .");
-    println!("       .         .   .   000     .        .       .");
-    println!(".. .. ..................O000O........................ ...... ...");
-    println!("... .. .......... Advent of Code 2023 ................... ... ..");
-
-    _day3_part1();
+#[cfg(test)]
+mod example_tests {
+    aoc_common::example_tests!(crate::year(),
+        day1_example1,
+        day1_example2,
+        day2_example1,
+        day3_example1,
+    );
 }
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_embed::build_script();
+}
@@ -0,0 +1,649 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use aoc_common::grid::{Direction, Grid, Point};
+use aoc_common::parse::{parse, Columns};
+use aoc_common::wordsearch::{Pattern, WordSearch};
+use aoc_common::{puzzle, Year};
+
+/// All of the 2024 puzzles that have been solved so far
+pub fn year() -> Year {
+    #[cfg(feature = "embed")]
+    aoc_common::input::embed(include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs")));
+
+    Year::new(2024, env!("CARGO_MANIFEST_DIR"), vec![
+        puzzle!(1, "Historian Hysteria", day1_parse, day1_part1, day1_part2),
+        puzzle!(2, "Red-Nosed Reports", day2_parse, day2_part1, day2_part2),
+        puzzle!(3, "Mull It Over", day3_parse, day3_part1, day3_part2),
+        puzzle!(4, "Ceres Search", day4_parse, day4_part1, day4_part2),
+        puzzle!(5, "Print Queue", day5_parse, day5_part1, day5_part2),
+        puzzle!(6, "Guard Gallivant", day6_parse, day6_part1, day6_part2).with_frames(day6_frames),
+    ])
+}
+
+/// Splits the input into a Vector of Strings for each line
+fn read_lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// The two columns of location IDs
+type LocationLists = (Vec<i32>, Vec<i32>);
+
+fn day1_parse(input: &str) -> LocationLists {
+    parse::<Columns<(i32, i32)>>(input).unzip()
+}
+
+fn day1_part1(location_lists: &LocationLists) -> i32 {
+    let mut col1 = location_lists.0.clone();
+    let mut col2 = location_lists.1.clone();
+
+    col1.sort();
+    col2.sort();
+
+    let mut total_distance = 0;
+    for (ii, val) in col1.iter().enumerate() {
+        let distance = val - col2[ii];
+
+        total_distance += distance.abs();
+    }
+
+    total_distance
+}
+
+fn day1_part2(location_lists: &LocationLists) -> i32 {
+    let mut col2_count_map: HashMap<i32, i32> = HashMap::new();
+    for col2_value in location_lists.1.iter() {
+        // Increment the count for the number of times the value has been seen in the second column
+        let count = col2_count_map.entry(*col2_value).or_insert(0);
+        *count += 1;
+    }
+
+    let mut total_similarity = 0;
+
+    // Loop through all the values from column 1 to see how many times they show up in column 2
+    for col1_value in location_lists.0.iter() {
+        if let Some(similarity) = col2_count_map.get(col1_value) {
+            total_similarity += col1_value * similarity;
+        }
+    }
+
+    total_similarity
+}
+
+fn day2_parse(input: &str) -> Vec<Vec<i32>> {
+    read_lines(input).iter()
+        .map(|report_raw| report_raw.split(' ').map(|x| x.trim().parse::<i32>().unwrap()).collect())
+        .collect()
+}
+
+fn day2_part1(reports: &[Vec<i32>]) -> i32 {
+    const MAX_LEVEL_CHANGE: i32 = 3;
+    let mut total_safe_reports = 0;
+
+    for report in reports {
+        if is_report_safe(report, MAX_LEVEL_CHANGE, None) {
+            total_safe_reports += 1;
+        }
+    }
+
+    total_safe_reports
+}
+
+fn day2_part2(reports: &[Vec<i32>]) -> i32 {
+    const MAX_LEVEL_CHANGE: i32 = 3;
+    let mut total_safe_reports = 0;
+
+    for report in reports {
+        if is_report_safe(report, MAX_LEVEL_CHANGE, None) {
+            total_safe_reports += 1;
+        } else {
+            // If the report is unsafe, iterate through the report while skipping 1 element at a time
+            for index in 0..report.len() {
+                if is_report_safe(report, MAX_LEVEL_CHANGE, Some(index)) {
+                    // Any report that can be considered safe by skipping a single step should be counted
+                    total_safe_reports += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    total_safe_reports
+}
+
+fn is_report_safe(report: &[i32], max_level_change: i32, skip_index: Option<usize>) -> bool {
+    let mut is_increasing: Option<bool> = None;
+    let mut is_safe: bool = true;
+
+    // Create a local copy that can be modified. This allows us to use the windows iterator more easily.
+    // It would probably be more efficient to write the for loop such that it skips the index while
+    // checking each item instead of creating a clone.
+    let mut local_report: Vec<i32> = report.to_vec();
+
+    if let Some(index) = skip_index {
+        local_report.remove(index);
+    }
+
+    for level_window in local_report.windows(2) {
+        let difference = level_window[1] - level_window[0];
+
+        if difference == 0 {
+            // If there is no change in the levels, we already know this is an unsafe report since they
+            // must all increase or decrease
+            is_safe = false;
+            break;
+        }
+
+        // If is_increasing has not yet been set, use the first time through as the value it should be for
+        // the entire set of numbers
+        if let Some(local_is_increasing) = is_increasing {
+            if (difference > 0) != local_is_increasing {
+                // If the current increase/decrease is different than the saved value, this report is unsafe
+                is_safe = false;
+                break;
+            }
+        }
+
+        is_increasing = Some(difference > 0);
+
+        if difference.abs() > max_level_change {
+            is_safe = false;
+            break;
+        }
+    }
+    is_safe
+}
+
+fn day3_parse(input: &str) -> Vec<String> {
+    read_lines(input)
+}
+
+fn day3_part1(day3_input: &[String]) -> i32 {
+    let valid_characters: Vec<char> = ['m', 'u', 'l', '(', ')', ',', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'].to_vec();
+
+    let mut total = 0;
+    for line in day3_input {
+        // Get rid of all the known junk characters
+        let filtered_input: String = line.chars().filter(|x| valid_characters.contains(x)).collect();
+
+        // println!(">>>> {:?}", filtered_input);
+
+        if let Some(value) = get_multiple(&filtered_input) {
+            total += value;
+        }
+    }
+
+    total
+}
+
+fn get_multiple(input: &str) -> Option<i32> {
+    if let Some(mul_tokens) = input.split_once("mul(") {
+        let mut multipler_stash: String = "".to_owned();
+
+        let mut multiplier1: i32 = 0;
+        let mut multiplier2: i32 = 0;
+
+        let mut next: bool = false;
+        let mut total: i32 = 0;
+
+        for (index, character) in mul_tokens.1.chars().enumerate() {
+            if character.is_numeric() {
+                multipler_stash.push(character);
+            } else if character == ',' {
+                if let Ok(value) = multipler_stash.parse::<i32>() {
+                    multiplier1 = value;
+                }
+
+                multipler_stash.clear();
+            } else if character == ')' {
+                if let Ok(value) = multipler_stash.parse::<i32>() {
+                    multiplier2 = value;
+                }
+
+                multipler_stash.clear();
+
+                // println!(">>>> {} * {}", multiplier1, multiplier2);
+                total = multiplier1 * multiplier2;
+
+                next = true;
+            } else {
+                multiplier1 = 0;
+                multiplier2 = 0;
+                multipler_stash.clear();
+
+                next = true;
+            }
+
+            if next {
+                let substring: String = mul_tokens.1.chars().skip(index).collect();
+                if let Some(value) = get_multiple(substring.borrow()) {
+                    total += value;
+                }
+
+                break;
+            }
+        }
+
+        Some(total)
+    } else {
+        None
+    }
+}
+
+#[derive(PartialEq)]
+enum CommandDay3 {
+    NoCommand,
+    Multiply,
+    Do,
+    Dont,
+}
+
+impl std::fmt::Display for CommandDay3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let command = match self {
+            CommandDay3::Multiply => "Multiply",
+            CommandDay3::Do => "Do",
+            CommandDay3::Dont => "Don\'t",
+            CommandDay3::NoCommand => "No Command",
+        };
+        write!(f, "{}", command)
+    }
+}
+
+#[derive(PartialEq)]
+enum FinderState {
+    Scanning,
+    ScanningForMul1,
+    ScanningForMul2,
+    ScanningForMul3,
+    ScanningForDoOrDont,
+    ScanningForDont,
+}
+
+fn day3_part2(day3_input: &[String]) -> i32 {
+    let _valid_characters: Vec<char> = ['m', 'u', 'l', 'd', 'o', 'n', '\'', 't', '(', ')', ',', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'].to_vec();
+
+    let mut total: i32 = 0;
+    let mut should_execute_command: bool = true;
+
+    for line in day3_input {
+        // Get rid of all the known junk characters
+        // @todo The command pattern matching currently has a bug where filtering out the invalid characters yields the wrong result
+        // let filtered_input: String = line.chars().filter(|x| valid_characters.contains(x)).collect();
+        let filtered_input: &str = line;
+        let mut index: usize = 0;
+
+        while index < filtered_input.len() {
+            let result = get_next_command(filtered_input, index);
+            index = result.1;
+            let current_cmd = result.0;
+
+            if current_cmd == CommandDay3::Dont {
+                should_execute_command = false;
+            } else if current_cmd == CommandDay3::Do {
+                should_execute_command = true;
+            }
+
+            if should_execute_command && current_cmd == CommandDay3::Multiply {
+                if let Some((multiplier1, multiplier2)) = get_mul_inputs(filtered_input, index) {
+                    total += multiplier1 * multiplier2;
+                }
+            }
+        }
+    }
+
+    total
+}
+
+fn get_next_command(input: &str, start_index: usize) -> (CommandDay3, usize) {
+    let mut state = FinderState::Scanning;
+
+    for (ii, character) in input.chars().skip(start_index).enumerate() {
+        match character {
+            'm' => {
+                state = FinderState::ScanningForMul1;
+            },
+            'u' => {
+                if state != FinderState::ScanningForMul1 {
+                    state = FinderState::Scanning;
+                } else {
+                    state = FinderState::ScanningForMul2
+                }
+            },
+            'l' => {
+                if state != FinderState::ScanningForMul2 {
+                    state = FinderState::Scanning;
+                } else {
+                    state = FinderState::ScanningForMul3
+                }
+            },
+            'd' => {
+                state = FinderState::ScanningForDoOrDont;
+            },
+            'o' => {
+                if state != FinderState::ScanningForDoOrDont {
+                    state = FinderState::Scanning;
+                }
+            },
+            'n' => {
+                if state != FinderState::ScanningForDoOrDont {
+                    state = FinderState::Scanning;
+                } else {
+                    state = FinderState::ScanningForDont;
+                }
+            },
+            '\'' => {
+                if state != FinderState::ScanningForDont {
+                    state = FinderState::Scanning;
+                }
+            },
+            't' => {
+                if state != FinderState::ScanningForDont {
+                    state = FinderState::Scanning;
+                }
+            },
+            '(' => {
+                match state {
+                    FinderState::ScanningForMul3 => return (CommandDay3::Multiply, ii + start_index),
+                    FinderState::ScanningForDoOrDont => return (CommandDay3::Do, ii + start_index),
+                    FinderState::ScanningForDont => return (CommandDay3::Dont, ii + start_index),
+                    _ => {
+                        state = FinderState::Scanning;
+                    },
+                }
+            }
+            _ => {
+                state = FinderState::Scanning;
+            },
+        }
+    }
+
+    (CommandDay3::NoCommand, input.len())
+}
+
+fn get_mul_inputs(input: &str, start_index: usize) -> Option<(i32, i32)> {
+    let mut multipler_stash: String = "".to_owned();
+
+    let mut multiplier1: i32 = 0;
+
+    for character in input.chars().skip(start_index) {
+        if character == '(' {
+            // Nothing to do here. Let the ( go on by
+        } else if character.is_numeric() {
+            multipler_stash.push(character);
+        } else if character == ',' {
+            if let Ok(value) = multipler_stash.parse::<i32>() {
+                multiplier1 = value;
+            } else {
+                return None
+            }
+
+            multipler_stash.clear();
+        } else if character == ')' {
+            if let Ok(value) = multipler_stash.parse::<i32>() {
+                return Some((multiplier1, value))
+            } else {
+                return None
+            }
+        } else {
+            return None
+        }
+    }
+
+    None
+}
+
+fn day4_parse(input: &str) -> Grid<char> {
+    parse::<Grid<char>>(input)
+}
+
+fn day4_part1(grid: &Grid<char>) -> usize {
+    // XMAS can be read in any direction, even backwards or diagonally, and overlapping other words
+    WordSearch::new(grid).find("XMAS").len()
+}
+
+fn day4_part2(grid: &Grid<char>) -> usize {
+    // Two MASes crossing at the A, each of which can be read either way, which is the same as turning the X around
+    let x_mas = Pattern::new("M.S\n.A.\nM.S", '.').with_rotations();
+
+    WordSearch::new(grid).find_pattern(&x_mas).len()
+}
+
+/// Page ordering rules, where the value in the HashMap represents the page numbers that must come after the key,
+/// along with the list of page updates
+type PrintQueue = (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>);
+
+fn day5_parse(input: &str) -> PrintQueue {
+    let mut page_rules: HashMap<i32, Vec<i32>> = HashMap::new();
+    let mut page_updates: Vec<Vec<i32>> = vec![];
+
+    for line in read_lines(input) {
+        if line.contains('|') {
+            if let Some(raw_rules) = line.split_once('|') {
+                // Build up a rule set for the sorting order of each value. The value in the HashMap represents the
+                // page numbers that must come after the key.
+                let key: i32 = raw_rules.0.parse::<i32>().unwrap();
+                let value: i32 = raw_rules.1.parse::<i32>().unwrap();
+
+                page_rules.entry(key).and_modify(|val_list| val_list.push(value)).or_insert(vec![value]);
+            }
+        } else if line.contains(',') {
+            let update: Vec<i32> = line.split(',').map(|x| x.trim().parse::<i32>().unwrap()).collect();
+            page_updates.push(update);
+        }
+    }
+
+    (page_rules, page_updates)
+}
+
+/// Adds up the middle page numbers of the updates, either the ones that were already in the right order or the ones
+/// that had to be fixed
+fn sum_middle_pages(print_queue: &PrintQueue, fixed: bool) -> i32 {
+    let (page_rules, page_updates) = print_queue;
+
+    let mut total: i32 = 0;
+    let mut fixed_total: i32 = 0;
+
+    // For each page update in the list, run a sort. If the order changes, then we know the update is invalid.
+    for original in page_updates.iter() {
+        // Keep the original so we can compare after it is sorted
+        let mut update = original.clone();
+
+        update.sort_by(|a, b| {
+            if let Some(order_rules) = page_rules.get(a) {
+                for rhs in order_rules {
+                    if b == rhs {
+                        return std::cmp::Ordering::Less
+                    }
+                }
+            }
+
+            std::cmp::Ordering::Equal
+        });
+
+        if &update == original {
+            total += update.get(update.len() / 2).unwrap();
+        } else {
+            fixed_total += update.get(update.len() / 2).unwrap();
+        }
+    }
+
+    if fixed {
+        fixed_total
+    } else {
+        total
+    }
+}
+
+fn day5_part1(print_queue: &PrintQueue) -> i32 {
+    sum_middle_pages(print_queue, false)
+}
+
+fn day5_part2(print_queue: &PrintQueue) -> i32 {
+    sum_middle_pages(print_queue, true)
+}
+
+/// Where the guard is and which way they're facing
+#[derive(Copy, Clone, PartialEq)]
+struct Guard {
+    position: Point,
+    direction: Direction,
+}
+
+/// Moves the guard a step forward, or turns them right if something is in the way. Returns false once the guard
+/// would walk off the map, leaving them where they are.
+fn move_guard(guard: &mut Guard, grid: &Grid<char>) -> bool {
+    const OBSTRUCTION: char = '#';
+
+    match grid.get(guard.position.step(guard.direction)) {
+        None => return false,
+        Some(&OBSTRUCTION) => guard.direction = guard.direction.turn_right(),
+        Some(_) => guard.position = guard.position.step(guard.direction),
+    }
+
+    true
+}
+
+/// The lab map along with where the guard starts out
+struct GuardMap {
+    grid: Grid<char>,
+    guard: Guard,
+}
+
+fn day6_parse(input: &str) -> GuardMap {
+    const GUARD_START_N: char = '^';
+
+    let grid: Grid<char> = parse::<Grid<char>>(input);
+    let position = grid.find(&GUARD_START_N).expect("The map should show where the guard starts");
+
+    GuardMap { grid, guard: Guard { position, direction: Direction::North } }
+}
+
+/// Walks the guard until they leave the map, marking every cell they stand on with an X. The callback sees the map
+/// and the guard at the start and after every move.
+fn patrol(guard_map: &GuardMap, mut on_step: impl FnMut(&Grid<char>, &Guard)) -> Grid<char> {
+    const VISITED_POINT: char = 'X';
+
+    let mut grid: Grid<char> = guard_map.grid.clone();
+    let mut guard: Guard = guard_map.guard;
+
+    grid[guard.position] = VISITED_POINT;
+    on_step(&grid, &guard);
+    while move_guard(&mut guard, &grid) {
+        grid[guard.position] = VISITED_POINT;
+        on_step(&grid, &guard);
+    }
+
+    grid
+}
+
+fn day6_part1(guard_map: &GuardMap) -> usize {
+    const VISITED_POINT: char = 'X';
+
+    patrol(guard_map, |_, _| {}).count(&VISITED_POINT)
+}
+
+/// The guard's patrol a step at a time, with the guard drawn as an arrow
+fn day6_frames(input: &str, _part: u8, frame: &mut dyn FnMut(&Grid<char>)) {
+    patrol(&day6_parse(input), |grid, guard| {
+        let mut grid: Grid<char> = grid.clone();
+        grid[guard.position] = match guard.direction {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            _ => '<',
+        };
+
+        frame(&grid);
+    });
+}
+
+fn day6_part2(guard_map: &GuardMap) -> usize {
+    const VISITED_POINT: char = 'X';
+    const OBSTRUCTION: char = '#';
+    const OBSTRUCTION_DONE: char = '+';
+
+    let mut total:usize = 0;
+    let mut grid: Grid<char> = guard_map.grid.clone();
+    let mut slow_guard: Guard = guard_map.guard;
+    let mut fast_guard: Guard;
+
+    let start_pose: Guard = slow_guard;
+    let starting_grid: Grid<char> = grid.clone();
+
+    // Generate the list of possible locations for an obstruction
+    while move_guard(&mut slow_guard, &grid) {
+        grid[slow_guard.position] = VISITED_POINT;
+    }
+
+    let possible_grid: Grid<char> = grid.clone();
+
+    // Reset the original grid to make it easier to see what's happening
+    grid = starting_grid.clone();
+
+    for (point, position) in possible_grid.iter() {
+        if point == start_pose.position {
+            // Skip the starting guard position
+            continue;
+        }
+        // Reset the guard to the starting pose
+        slow_guard = start_pose;
+        fast_guard = start_pose;
+
+        // Only run the simulation for locations where the guard will traverse. If the guard never crosses
+        // a given point, putting an obstacle at that point would not change the outcome.
+        if *position == VISITED_POINT {
+            grid[point] = OBSTRUCTION;
+
+            let mut keep_going: bool = true;
+
+            while keep_going {
+                // Using the tortoise and hare algorithm, have the slow guard move 1 position and the
+                // fast guard move 2 positions. If the fast guard catches the slow guard going the same
+                // direction at the same point, then this is a loop.
+                move_guard(&mut slow_guard, &grid);
+
+                // If the fast guard ever exits the grid, this is not a loop
+                keep_going = move_guard(&mut fast_guard, &grid);
+
+                if keep_going {
+                    keep_going = move_guard(&mut fast_guard, &grid);
+                }
+
+                if fast_guard == slow_guard {
+                    total += 1;
+                    keep_going = false;
+                }
+            }
+
+            grid[point] = OBSTRUCTION_DONE;
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod solve_tests {
+    use crate::year;
+
+    #[test]
+    fn test_solve_from_text() {
+        assert_eq!(year().solve(1, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"), Ok("11".to_string()));
+        assert!(year().solve(1, 3, "").is_err());
+        assert!(year().solve(26, 1, "").is_err());
+    }
+}
+
+#[cfg(test)]
+mod example_tests {
+    aoc_common::example_tests!(crate::year(),
+        day1_example1,
+        day2_example1,
+        day3_example1,
+        day3_example2,
+        day4_example1,
+        day5_example1,
+        day6_example1,
+    );
+}
@@ -0,0 +1,249 @@
+//! Cellular automata: grids where every cell changes at once, following a rule about the cell and its neighbours.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+use crate::grid::{Direction, Grid, Point};
+
+/// Which cells count as a cell's neighbours
+#[derive(Clone, Copy, Debug)]
+pub enum Neighbourhood<T> {
+    /// The eight cells around it
+    Moore,
+    /// The four cells that share a side with it
+    VonNeumann,
+    /// The nearest cell in each of the eight directions that the function doesn't say to look past. Which cells can
+    /// be seen is worked out from the starting grid, so the cells being looked past shouldn't ever change.
+    LineOfSight(fn(&T) -> bool),
+}
+
+impl<T> Neighbourhood<T> {
+    /// The neighbours of the point on the grid
+    fn of(&self, grid: &Grid<T>, point: Point) -> Vec<Point> {
+        match self {
+            Neighbourhood::Moore => grid.neighbors8(point).collect(),
+            Neighbourhood::VonNeumann => grid.neighbors4(point).collect(),
+            Neighbourhood::LineOfSight(look_past) => Direction::ALL.iter()
+                .filter_map(|direction| grid.ray(point, *direction).find(|(_, cell)| !look_past(cell)))
+                .map(|(point, _)| point)
+                .collect(),
+        }
+    }
+}
+
+/// A cell's neighbours, as the rule sees them
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    points: &'a [Point],
+}
+
+impl<'a, T> Neighbours<'a, T> {
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.points.iter().map(|point| &self.grid[*point])
+    }
+
+    /// How many of the neighbours hold the value
+    pub fn count(&self, value: &T) -> usize where T: PartialEq {
+        self.iter().filter(|cell| *cell == value).count()
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+/// Where the generations of an automaton start repeating
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first generation that comes round again
+    pub start: usize,
+    /// How many generations it takes to come round again. A grid that has stopped changing has a length of 1.
+    pub length: usize,
+}
+
+/// A grid that changes a generation at a time, with the rule giving each cell's next value from its current value and
+/// its neighbours
+pub struct Automaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    rule: R,
+    /// Each cell's neighbours, in reading order of the cells
+    neighbours: Vec<Vec<Point>>,
+    /// The cells whose next value depends on each cell: itself and every cell it's a neighbour of
+    dependents: Vec<Vec<Point>>,
+    /// The cells that could change in the next step
+    frontier: Vec<Point>,
+    /// Which cells are already in the frontier
+    queued: Grid<bool>,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R> where T: Clone + PartialEq, R: FnMut(&T, Neighbours<'_, T>) -> T {
+    pub fn new(grid: Grid<T>, neighbourhood: Neighbourhood<T>, rule: R) -> Automaton<T, R> {
+        let neighbours: Vec<Vec<Point>> = grid.points().map(|point| neighbourhood.of(&grid, point)).collect();
+
+        let mut dependents: Vec<Vec<Point>> = grid.points().map(|point| vec![point]).collect();
+        for (point, cell_neighbours) in grid.points().zip(&neighbours) {
+            for neighbour in cell_neighbours {
+                dependents[index_of(&grid, *neighbour)].push(point);
+            }
+        }
+
+        Automaton {
+            next: grid.clone(),
+            frontier: grid.points().collect(),
+            queued: grid.map(|_| true),
+            current: grid,
+            rule,
+            neighbours,
+            dependents,
+            generation: 0,
+        }
+    }
+
+    /// The current generation
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// How many steps have been taken
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Moves on to the next generation, returning whether any cell changed
+    pub fn step(&mut self) -> bool {
+        let mut changed: Vec<Point> = vec![];
+
+        // The next grid is the generation before the current one, which only differs from it in the cells that
+        // changed last time. Those are all in the frontier, so every cell left alone already has the right value.
+        for point in self.frontier.drain(..) {
+            self.queued[point] = false;
+
+            let points = &self.neighbours[index_of(&self.current, point)];
+            let neighbours = Neighbours { grid: &self.current, points };
+            let cell = (self.rule)(&self.current[point], neighbours);
+
+            if cell != self.current[point] {
+                changed.push(point);
+            }
+            self.next[point] = cell;
+        }
+
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+
+        for point in &changed {
+            for dependent in &self.dependents[index_of(&self.current, *point)] {
+                if !mem::replace(&mut self.queued[*dependent], true) {
+                    self.frontier.push(*dependent);
+                }
+            }
+        }
+
+        !changed.is_empty()
+    }
+
+    /// Steps until nothing changes, returning the generation it settled on. This never returns if the automaton
+    /// never settles, so `find_cycle` is the safer choice when that's possible.
+    pub fn settle(&mut self) -> usize {
+        while self.step() {}
+
+        // The last step didn't change anything, so the generation before it was already settled
+        self.generation - 1
+    }
+
+    /// Steps until a generation repeats one that's been seen before, counting from the current one
+    pub fn find_cycle(&mut self) -> Cycle where T: Eq + Hash {
+        let mut seen: HashMap<Grid<T>, usize> = HashMap::new();
+
+        loop {
+            if let Some(start) = seen.insert(self.current.clone(), self.generation) {
+                return Cycle { start, length: self.generation - start }
+            }
+
+            self.step();
+        }
+    }
+}
+
+/// Where the cell at the point is in the grid's reading order
+fn index_of<T>(grid: &Grid<T>, point: Point) -> usize {
+    point.y as usize * grid.width() + point.x as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, Cycle, Neighbourhood, Neighbours};
+    use crate::grid::{Grid, Point};
+    use crate::parse::parse;
+
+    /// Conway's Game of Life
+    fn life(cell: &char, neighbours: Neighbours<'_, char>) -> char {
+        match (cell, neighbours.count(&'#')) {
+            ('#', 2) | (_, 3) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn test_blinker() {
+        let mut automaton = Automaton::new(parse::<Grid<char>>(".....\n..#..\n..#..\n..#..\n.....\n"),
+                                           Neighbourhood::Moore, life);
+
+        assert!(automaton.step());
+        assert_eq!(automaton.grid().to_string(), ".....\n.....\n.###.\n.....\n.....\n");
+        assert!(automaton.step());
+        assert_eq!(automaton.generation(), 2);
+        assert_eq!(automaton.find_cycle(), Cycle { start: 2, length: 2 });
+    }
+
+    #[test]
+    fn test_glider() {
+        // A glider moves one cell diagonally every four generations, and goes through the same frontier handling
+        // as a real run, so compare it against working every cell out from scratch
+        let start: Grid<char> = parse(".#......\n..#.....\n###.....\n........\n........\n........\n");
+        let mut automaton = Automaton::new(start.clone(), Neighbourhood::Moore, life);
+        for _ in 0..4 {
+            automaton.step();
+        }
+
+        let moved = Grid::from_fn(start.width(), start.height(), |point| {
+            start.get(Point::new(point.x - 1, point.y - 1)).copied().unwrap_or('.')
+        });
+        assert_eq!(*automaton.grid(), moved);
+    }
+
+    #[test]
+    fn test_settle() {
+        // Spreading to the four cells around each # until the grid fills up
+        let mut automaton = Automaton::new(parse::<Grid<char>>("#...\n....\n"), Neighbourhood::VonNeumann,
+                                           |cell: &char, neighbours: Neighbours<'_, char>| {
+                                               if neighbours.count(&'#') > 0 { '#' } else { *cell }
+                                           });
+
+        assert_eq!(automaton.settle(), 4);
+        assert_eq!(automaton.grid().count(&'#'), 8);
+        assert_eq!(automaton.find_cycle(), Cycle { start: 5, length: 1 });
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        // Each # can see the nearest # in each direction, looking past the gaps
+        let grid: Grid<char> = parse("#..#\n....\n#.##\n");
+        let automaton = Automaton::new(grid, Neighbourhood::LineOfSight(|cell| *cell == '.'),
+                                       |cell: &char, _: Neighbours<'_, char>| *cell);
+
+        assert_eq!(automaton.neighbours[0], vec![Point::new(3, 0), Point::new(2, 2), Point::new(0, 2)]);
+        assert_eq!(automaton.neighbours[11], vec![Point::new(3, 0), Point::new(2, 2)]);
+    }
+}
@@ -0,0 +1,188 @@
+//! Encrypting puzzle inputs with ChaCha20-Poly1305, so they can be kept in git as `.enc` files.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+/// Environment variable holding the key as hex
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// Environment variable naming a file that holds the key as hex
+pub const KEY_FILE_VAR: &str = "AOC_INPUT_KEY_FILE";
+
+/// What gets added to an input's file name once it's encrypted
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// Marks the start of an encrypted input, along with the version of the format
+const HEADER: &[u8] = b"AOCENC1\n";
+
+const NONCE_LEN: usize = 12;
+
+/// A 256-bit key for encrypting inputs
+#[derive(Clone, PartialEq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// A new random key
+    pub fn generate() -> Result<Key, String> {
+        let mut key = [0; 32];
+        getrandom::getrandom(&mut key).map_err(|err| format!("Unable to generate a key: {}", err))?;
+
+        Ok(Key(key))
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Key, String> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err("An input key should be 64 hex digits".to_string())
+        }
+
+        let mut key = [0; 32];
+        for (index, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16)
+                .map_err(|_| "An input key should be 64 hex digits".to_string())?;
+        }
+
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+    }
+
+    /// The key given by `AOC_INPUT_KEY` or `AOC_INPUT_KEY_FILE`, or else the one in `.aoc-input-key` at the
+    /// workspace root, if there is one
+    pub fn from_env() -> Result<Option<Key>, String> {
+        if let Some(hex) = std::env::var_os(KEY_VAR).filter(|hex| !hex.is_empty()) {
+            let hex = hex.into_string().map_err(|_| format!("{} should be 64 hex digits", KEY_VAR))?;
+            return Key::from_hex(&hex).map(Some).map_err(|message| format!("{}: {}", KEY_VAR, message))
+        }
+
+        if let Some(path) = std::env::var_os(KEY_FILE_VAR).filter(|path| !path.is_empty()) {
+            return Key::from_file(Path::new(&path)).map(Some)
+        }
+
+        let path = default_key_path();
+        if path.exists() {
+            return Key::from_file(&path).map(Some)
+        }
+
+        Ok(None)
+    }
+
+    /// The key from the environment, with an error explaining how to give one if there isn't
+    pub fn required() -> Result<Key, String> {
+        Key::from_env()?.ok_or(format!("No input key given; set {} or {}, or save it in {}", KEY_VAR, KEY_FILE_VAR,
+                                       default_key_path().display()))
+    }
+
+    fn from_file(path: &Path) -> Result<Key, String> {
+        let hex = fs::read_to_string(path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+        Key::from_hex(&hex).map_err(|message| format!("{}: {}", path.display(), message))
+    }
+}
+
+/// Where the key is looked for when neither variable is set, which git ignores
+pub fn default_key_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join(".aoc-input-key")
+}
+
+/// Where the encrypted copy of the given input lives
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(ENCRYPTED_EXTENSION);
+
+    path.with_file_name(name)
+}
+
+/// Encrypts the text with a fresh random nonce
+pub fn encrypt(key: &Key, text: &str) -> Result<Vec<u8>, String> {
+    let mut nonce = [0; NONCE_LEN];
+    getrandom::getrandom(&mut nonce).map_err(|err| format!("Unable to generate a nonce: {}", err))?;
+
+    let ciphertext = ChaCha20Poly1305::new(&key.0.into())
+        .encrypt(Nonce::from_slice(&nonce), text.as_bytes())
+        .map_err(|_| "Unable to encrypt input".to_string())?;
+
+    Ok([HEADER, &nonce, &ciphertext].concat())
+}
+
+/// Decrypts something written by `encrypt`, failing if the key is wrong or the data has been changed
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<String, String> {
+    let data = data.strip_prefix(HEADER).ok_or("Not an encrypted input")?;
+    if data.len() < NONCE_LEN {
+        return Err("Encrypted input is truncated".to_string())
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    let text = ChaCha20Poly1305::new(&key.0.into())
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Unable to decrypt input; the key is wrong or the file is damaged".to_string())?;
+
+    String::from_utf8(text).map_err(|_| "Decrypted input isn't valid UTF-8".to_string())
+}
+
+/// Reads and decrypts an encrypted input file
+pub fn read_encrypted(path: &Path, key: &Key) -> Result<String, String> {
+    let data = fs::read(path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+
+    decrypt(key, &data).map_err(|message| format!("{}: {}", path.display(), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{decrypt, default_key_path, encrypt, encrypted_path, Key};
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::generate().unwrap();
+        let data = encrypt(&key, "1721\n979\n366\n").unwrap();
+
+        assert_eq!(decrypt(&key, &data).unwrap(), "1721\n979\n366\n");
+        assert_ne!(encrypt(&key, "1721\n979\n366\n").unwrap(), data);
+    }
+
+    #[test]
+    fn test_wrong_key_or_damaged_data() {
+        let key = Key::generate().unwrap();
+        let mut data = encrypt(&key, "abc").unwrap();
+
+        assert!(decrypt(&Key::generate().unwrap(), &data).is_err());
+        assert!(decrypt(&key, b"abc").is_err());
+
+        let last = data.len() - 1;
+        data[last] ^= 1;
+        assert!(decrypt(&key, &data).is_err());
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let hex = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        assert_eq!(Key::from_hex(hex).unwrap().to_hex(), hex);
+        assert_eq!(Key::from_hex(&format!("{}\n", hex.to_uppercase())).unwrap().to_hex(), hex);
+        assert!(Key::from_hex(&hex[2..]).is_err());
+        assert!(Key::from_hex(&hex.replace('0', "g")).is_err());
+    }
+
+    #[test]
+    fn test_default_key_path() {
+        let path = default_key_path();
+        assert_eq!(path.file_name().unwrap(), ".aoc-input-key");
+        assert!(path.parent().unwrap().join("aoc-common").is_dir());
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(encrypted_path(Path::new("/repo/aoc-2024/input/day6_input.txt")),
+                   Path::new("/repo/aoc-2024/input/day6_input.txt.enc"));
+    }
+}
@@ -0,0 +1,125 @@
+//! Examples from the puzzle descriptions, checked by `cargo test`. Each file has a `part N: answer` line per part,
+//! then a `---` line and the example input.
+
+use std::fs;
+
+use crate::input::{embedded_paths, read_input};
+use crate::Year;
+
+/// An example input along with the answers the puzzle description expects for it
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(u8, String)>,
+}
+
+impl Example {
+    pub fn parse(text: &str) -> Result<Example, String> {
+        let mut lines = text.split_inclusive('\n');
+        let mut answers = vec![];
+
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line == "---" {
+                return Ok(Example { input: lines.collect(), answers })
+            }
+            if line.is_empty() {
+                continue
+            }
+
+            let (part, answer) = line.strip_prefix("part ")
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or(format!("Expected a `part N: answer` line, found: {}", line))?;
+            let part = part.trim().parse::<u8>().map_err(|_| format!("Invalid part: {}", part))?;
+
+            answers.push((part, answer.trim().to_string()));
+        }
+
+        Err("Missing the `---` line between the answers and the input".to_string())
+    }
+}
+
+impl Year {
+    /// The names of every example file for this year, without the extension, including any compiled into the binary
+    pub fn example_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(&self.examples_dir)
+            .map(|entries| entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .chain(embedded_paths(&self.examples_dir))
+            .filter_map(|path| path.file_name()?.to_str()?.strip_suffix(".txt").map(str::to_string))
+            .collect();
+        names.sort();
+        names.dedup();
+
+        names
+    }
+}
+
+/// Solves the named example and panics if any answer differs from the one in the example file. The day is taken
+/// from the start of the name, so `day3_example2` is the second example for day 3.
+pub fn check_example(year: &Year, name: &str) {
+    let day = name.strip_prefix("day")
+        .and_then(|rest| rest.split('_').next())
+        .and_then(|day| day.parse::<u8>().ok())
+        .unwrap_or_else(|| panic!("Example names should start with the day, such as day3_example1: {}", name));
+
+    let path = year.examples_dir.join(format!("{}.txt", name));
+    let text = read_input(&path).unwrap_or_else(|message| panic!("{}", message));
+    let example = Example::parse(&text).unwrap_or_else(|message| panic!("{}: {}", path.display(), message));
+
+    let puzzle = year.puzzle(day).unwrap_or_else(|| panic!("{} has no solution for day {}", year.year, day));
+    let parts: Vec<u8> = example.answers.iter().map(|(part, _)| *part).collect();
+    let run = puzzle.solve(&example.input, &parts);
+
+    for ((part, expected), result) in example.answers.iter().zip(run.parts) {
+        match result.answer {
+            Ok(answer) => assert_eq!(&answer, expected, "{} part {}", name, part),
+            Err(message) => panic!("{} part {} failed: {}", name, part, message),
+        }
+    }
+}
+
+/// Turns each named example file into a `#[test]` that checks the solution against the example's answers. It also
+/// adds a test making sure every example file in the year's examples directory has been listed.
+///
+/// ```ignore
+/// example_tests!(crate::year(), day1_example1, day1_example2);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($year:expr, $($example:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $example() {
+                $crate::check_example(&$year, stringify!($example));
+            }
+        )+
+
+        #[test]
+        fn every_example_is_tested() {
+            let tested = vec![$(stringify!($example)),+];
+            let untested: Vec<String> = $year.example_names().into_iter()
+                .filter(|name| !tested.contains(&name.as_str()))
+                .collect();
+
+            assert!(untested.is_empty(), "Add these examples to example_tests!: {:?}", untested);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Example;
+
+    #[test]
+    fn test_parse_example() {
+        let example = Example::parse("part 1: 7\npart 2: 336\n---\n..##\n#...\n").unwrap();
+        assert_eq!(example.answers, vec![(1, "7".to_string()), (2, "336".to_string())]);
+        assert_eq!(example.input, "..##\n#...\n");
+
+        assert_eq!(Example::parse("part 2: 281\n---\n\n1abc2\n").unwrap().input, "\n1abc2\n");
+        assert!(Example::parse("part 1: 7\n..##\n").is_err());
+        assert!(Example::parse("answer: 7\n---\n..##\n").is_err());
+    }
+}
@@ -0,0 +1,397 @@
+//! A rectangular grid of cells. Points count `x` to the right and `y` down from the top left cell at (0, 0).
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub};
+
+/// A position on a grid, or an offset between two positions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// The point one step away in the given direction
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    /// The four points sharing an edge with this one, clockwise from north
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::CARDINAL.into_iter().map(move |direction| self.step(direction))
+    }
+
+    /// The eight points sharing an edge or a corner with this one, clockwise from north
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |direction| self.step(direction))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+/// Points are ordered the way the grid is read: row by row from the top, then left to right
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> std::cmp::Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the eight directions on a grid, with north towards the top row
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest,
+    ];
+
+    /// The four directions along the rows and columns, clockwise from north
+    pub const CARDINAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// The four diagonal directions, clockwise from north east
+    pub const DIAGONAL: [Direction; 4] =
+        [Direction::NorthEast, Direction::SouthEast, Direction::SouthWest, Direction::NorthWest];
+
+    /// How far a single step in this direction moves
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by the given number of eighths of a turn
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+/// A rectangular grid stored row by row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        assert!(rows.iter().all(|row| row.len() == width), "Every row of a grid must be the same length");
+
+        Grid { width, height: rows.len(), cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// A grid of the given size with every cell worked out from its position
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+            .map(&mut cell)
+            .collect();
+
+        Grid { width, height, cells }
+    }
+
+    /// A grid of the given size with every cell set to the same value
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() refuses a size of zero, which an empty grid would otherwise ask for
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<T>> where T: Clone {
+        self.rows().map(<[T]>::to_vec).collect()
+    }
+
+    pub fn in_bounds(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.in_bounds(point).then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    /// The cell at the point, or `None` if the point is outside the grid
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(move |index| &mut self.cells[index])
+    }
+
+    /// Every position in the grid, in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Point::new((index % width) as i64, (index / width) as i64))
+    }
+
+    /// Every cell along with its position, in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The neighbours of the point that share an edge with it and are inside the grid
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbors4().filter(move |neighbor| self.in_bounds(*neighbor))
+    }
+
+    /// The neighbours of the point that share an edge or corner with it and are inside the grid
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbors8().filter(move |neighbor| self.in_bounds(*neighbor))
+    }
+
+    /// The cells seen looking from the point in the given direction, nearest first, up to the edge of the grid. The
+    /// starting cell itself isn't included.
+    pub fn ray(&self, from: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> + '_ {
+        std::iter::successors(Some(from.step(direction)), move |point| Some(point.step(direction)))
+            .map_while(move |point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The position of every cell matching the predicate, in reading order
+    pub fn positions(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Point> {
+        self.cells.iter()
+            .enumerate()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(index, _)| self.point_of(index))
+            .collect()
+    }
+
+    /// The position of every cell equal to the value, in reading order
+    pub fn find_all(&self, value: &T) -> Vec<Point> where T: PartialEq {
+        self.positions(|cell| cell == value)
+    }
+
+    /// The first position, in reading order, holding the value
+    pub fn find(&self, value: &T) -> Option<Point> where T: PartialEq {
+        self.cells.iter().position(|cell| cell == value).map(|index| self.point_of(index))
+    }
+
+    /// How many cells are equal to the value
+    pub fn count(&self, value: &T) -> usize where T: PartialEq {
+        self.cells.iter().filter(|cell| *cell == value).count()
+    }
+
+    /// A grid of the same size with every cell converted
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(cell).collect() }
+    }
+
+    /// The grid flipped over its main diagonal, so rows become columns
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.height, self.width, |point| self[Point::new(point.y, point.x)].clone())
+    }
+
+    /// The grid turned a quarter turn clockwise
+    pub fn rotate_right(&self) -> Grid<T> where T: Clone {
+        let height = self.height as i64;
+        Grid::from_fn(self.height, self.width, |point| self[Point::new(point.y, height - 1 - point.x)].clone())
+    }
+
+    /// The grid turned a quarter turn anticlockwise
+    pub fn rotate_left(&self) -> Grid<T> where T: Clone {
+        let width = self.width as i64;
+        Grid::from_fn(self.height, self.width, |point| self[Point::new(width - 1 - point.y, point.x)].clone())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// The cell at the point, which must be inside the grid
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{} is outside the {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point).unwrap_or_else(|| panic!("{} is outside the {}x{} grid", point, width, height))
+    }
+}
+
+/// Shows the grid a row per line, with nothing between the cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Grid, Point};
+    use crate::parse::parse;
+
+    fn grid() -> Grid<char> {
+        parse::<Grid<char>>("abc\ndef\n")
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid();
+
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&'c'));
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&'d'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid[Point::new(1, 1)], 'e');
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+
+        let neighbors: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(neighbors, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+        assert_eq!(Point::new(5, 5).neighbors8().count(), 8);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = grid();
+
+        let east: String = grid.ray(Point::new(0, 0), Direction::East).map(|(_, cell)| cell).collect();
+        assert_eq!(east, "bc");
+        let south_east: Vec<Point> = grid.ray(Point::new(0, 0), Direction::SouthEast).map(|(point, _)| point).collect();
+        assert_eq!(south_east, vec![Point::new(1, 1)]);
+        assert_eq!(grid.ray(Point::new(0, 0), Direction::North).count(), 0);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = parse::<Grid<char>>("#.#\n.#.\n");
+
+        assert_eq!(grid.find_all(&'#'), vec![Point::new(0, 0), Point::new(2, 0), Point::new(1, 1)]);
+        assert_eq!(grid.find(&'.'), Some(Point::new(1, 0)));
+        assert_eq!(grid.count(&'#'), 3);
+        assert_eq!(grid.find(&'x'), None);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|cell| cell.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        assert_eq!(Point::new(2, 3).step(Direction::North), Point::new(2, 2));
+        assert!(Point::new(5, 0) < Point::new(0, 1));
+    }
+}
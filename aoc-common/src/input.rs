@@ -0,0 +1,171 @@
+//! Finding and reading puzzle inputs.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::crypt::{self, Key};
+
+/// The name of the directory holding each year's inputs
+pub const INPUT_DIR: &str = "input";
+
+/// Environment variable pointing at a directory of inputs to use instead of the ones in each crate
+pub const INPUT_ROOT_VAR: &str = "AOC_INPUT_ROOT";
+
+/// Files compiled into the binary, by the path they were built from
+static EMBEDDED: Mutex<BTreeMap<&str, &[u8]>> = Mutex::new(BTreeMap::new());
+
+/// Uses the given files, compiled into the binary, in place of the files they were built from. See the `aoc-embed` crate.
+pub fn embed(files: &'static [(&'static str, &'static [u8])]) {
+    EMBEDDED.lock().unwrap().extend(files.iter().copied());
+}
+
+fn embedded(path: &Path) -> Option<&'static [u8]> {
+    EMBEDDED.lock().unwrap().get(path.to_str()?).copied()
+}
+
+/// The paths of the embedded files directly within the given directory
+pub fn embedded_paths(dir: &Path) -> Vec<PathBuf> {
+    EMBEDDED.lock().unwrap().keys()
+        .map(PathBuf::from)
+        .filter(|path| path.parent() == Some(dir))
+        .collect()
+}
+
+/// The inputs directory of the crate at the given path
+pub fn crate_input_dir(crate_dir: &Path) -> PathBuf {
+    crate_dir.join(INPUT_DIR)
+}
+
+/// Where a year's inputs live under a separate inputs root
+pub fn root_input_dir(root: &Path, year: u16) -> PathBuf {
+    root.join(year.to_string())
+}
+
+/// Where a year's inputs live, taking `AOC_INPUT_ROOT` into account
+pub fn input_dir(year: u16, crate_dir: &Path) -> PathBuf {
+    match std::env::var_os(INPUT_ROOT_VAR) {
+        Some(root) if !root.is_empty() => root_input_dir(Path::new(&root), year),
+        _ => crate_input_dir(crate_dir),
+    }
+}
+
+/// Where a user's input for the given day lives within the users directory
+pub fn user_input_path(users_dir: &Path, user: &str, year: u16, day: u8) -> PathBuf {
+    users_dir.join(user).join(year.to_string()).join(format!("{}.txt", day))
+}
+
+/// Whether a user name is safe to use as a directory in the users directory, so it can't reach outside of it
+pub fn is_valid_user(user: &str) -> bool {
+    !user.is_empty() && !user.starts_with('.') && !user.contains(['/', '\\']) && !user.contains("..")
+}
+
+/// Everyone with a directory of inputs in the users directory, sorted by name
+pub fn users(users_dir: &Path) -> Vec<String> {
+    let mut users: Vec<String> = fs::read_dir(users_dir)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| !name.starts_with('.'))
+            .collect())
+        .unwrap_or_default();
+    users.sort();
+
+    users
+}
+
+/// Whether there's an input at the path, whether plain, encrypted or embedded
+pub fn input_exists(path: &Path) -> bool {
+    let encrypted = crypt::encrypted_path(path);
+
+    path.exists() || encrypted.exists() || embedded(path).is_some() || embedded(&encrypted).is_some()
+}
+
+/// Reads an input file, falling back to its encrypted copy when there's no plain one. Embedded copies are used in
+/// place of either. The error names the path if it can't be read.
+pub fn read_input(path: &Path) -> Result<String, String> {
+    if let Some(input) = embedded(path) {
+        return String::from_utf8(input.to_vec()).map_err(|_| format!("{} isn't valid UTF-8", path.display()))
+    }
+    if let Some(data) = embedded(&crypt::encrypted_path(path)) {
+        let key = Key::required().map_err(|message| format!("{} is encrypted: {}", path.display(), message))?;
+        return crypt::decrypt(&key, data).map_err(|message| format!("{}: {}", path.display(), message))
+    }
+
+    match fs::read_to_string(path) {
+        Ok(input) => Ok(input),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            let encrypted = crypt::encrypted_path(path);
+            if !encrypted.exists() {
+                return Err(format!("No input found at {}", path.display()))
+            }
+
+            let key = Key::required().map_err(|message| format!("{} is encrypted: {}", path.display(), message))?;
+            crypt::read_encrypted(&encrypted, &key)
+        },
+        Err(err) => Err(format!("Unable to read {}: {}", path.display(), err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{crate_input_dir, embed, embedded_paths, input_exists, is_valid_user, read_input, root_input_dir,
+                user_input_path, users};
+
+    #[test]
+    fn test_input_dirs() {
+        assert_eq!(crate_input_dir(Path::new("/repo/aoc-2024")), Path::new("/repo/aoc-2024/input"));
+        assert_eq!(root_input_dir(Path::new("/inputs"), 2024), Path::new("/inputs/2024"));
+        assert_eq!(user_input_path(Path::new("/repo/inputs"), "sam", 2024, 6),
+                   Path::new("/repo/inputs/sam/2024/6.txt"));
+    }
+
+    #[test]
+    fn test_users() {
+        let users_dir = std::env::temp_dir().join(format!("aoc-users-test-{}", std::process::id()));
+        for user in ["sam", "alex", ".git"] {
+            std::fs::create_dir_all(users_dir.join(user).join("2024")).unwrap();
+        }
+        std::fs::write(users_dir.join("notes.txt"), "not a user").unwrap();
+
+        assert_eq!(users(&users_dir), vec!["alex", "sam"]);
+        assert!(users(&users_dir.join("missing")).is_empty());
+
+        std::fs::remove_dir_all(&users_dir).unwrap();
+    }
+
+    #[test]
+    fn test_is_valid_user() {
+        assert!(is_valid_user("sam"));
+        assert!(is_valid_user("alex.smith"));
+        assert!(!is_valid_user(""));
+        assert!(!is_valid_user(".git"));
+        assert!(!is_valid_user("../sam"));
+        assert!(!is_valid_user("sam/../alex"));
+        assert!(!is_valid_user("sam\\alex"));
+        assert!(!is_valid_user("/etc"));
+    }
+
+    #[test]
+    fn test_embedded_inputs() {
+        embed(&[("/embedded/aoc-2024/input/day1_input.txt", b"3   4\n"),
+                ("/embedded/aoc-2024/input/examples/day1_example1.txt", b"part 1: 11\n---\n3   4\n")]);
+
+        assert_eq!(read_input(Path::new("/embedded/aoc-2024/input/day1_input.txt")).unwrap(), "3   4\n");
+        assert!(input_exists(Path::new("/embedded/aoc-2024/input/day1_input.txt")));
+        assert!(!input_exists(Path::new("/embedded/aoc-2024/input/day2_input.txt")));
+        assert_eq!(embedded_paths(Path::new("/embedded/aoc-2024/input/examples")),
+                   vec![Path::new("/embedded/aoc-2024/input/examples/day1_example1.txt")]);
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let message = read_input(Path::new("/no/such/dir/day1_input.txt")).unwrap_err();
+        assert_eq!(message, "No input found at /no/such/dir/day1_input.txt");
+    }
+}
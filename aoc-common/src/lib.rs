@@ -0,0 +1,195 @@
+//! Shared pieces used by every year's solutions and by the `aoc` runner.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub use examples::{check_example, Example};
+
+pub mod automaton;
+pub mod crypt;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod path;
+pub mod region;
+pub mod render;
+pub mod sparse;
+pub mod spans;
+pub mod view;
+pub mod wordsearch;
+pub mod wrap;
+mod examples;
+
+type Solver = Box<dyn Fn(&str, &[u8]) -> PuzzleRun>;
+
+/// Plays out a puzzle's simulation for the given input and part, handing each state to the callback as a grid of
+/// characters. This is how simulations can be drawn or watched without the solution keeping every state around.
+pub type Frames = fn(input: &str, part: u8, frame: &mut dyn FnMut(&grid::Grid<char>));
+
+/// The outcome of solving one part of a puzzle
+pub struct PartRun {
+    pub part: u8,
+    /// The answer, or the panic message if the solution blew up (such as a failed check against a known answer)
+    pub answer: Result<String, String>,
+    pub solve_time: Duration,
+}
+
+/// The outcome of solving a puzzle, along with how long parsing the input took
+pub struct PuzzleRun {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl PuzzleRun {
+    /// Time spent parsing plus the time spent solving every part
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.solve_time).sum::<Duration>()
+    }
+}
+
+/// Pulls the message out of a panic payload so it can be reported instead of taking down the whole run
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_string()
+    }
+}
+
+/// Runs the closure and times it, catching any panic along the way
+fn timed<R>(operation: impl FnOnce() -> R) -> (Result<R, String>, Duration) {
+    let time = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(operation)).map_err(panic_message);
+
+    (result, time.elapsed())
+}
+
+/// A single day's puzzle: how to parse the input and how to solve each of its parts.
+pub struct Puzzle {
+    pub day: u8,
+    pub title: &'static str,
+    pub part_count: u8,
+    solver: Solver,
+    /// The puzzle's simulation, for puzzles that have one worth looking at
+    pub frames: Option<Frames>,
+}
+
+impl Puzzle {
+    /// Builds a puzzle from its parse function and part functions. The parsed input is shared by every part.
+    /// The `puzzle!` macro is usually easier to use since it takes care of converting the answers to strings.
+    pub fn new<T: 'static>(day: u8, title: &'static str, parse: fn(&str) -> T, parts: Vec<fn(&T) -> String>) -> Puzzle {
+        let part_count = parts.len() as u8;
+
+        Puzzle {
+            day,
+            title,
+            part_count,
+            solver: Box::new(move |input, parts_to_run| {
+                let (parsed, parse_time) = timed(|| parse(input));
+
+                let parts = parts_to_run.iter()
+                    .map(|part| {
+                        let (answer, solve_time) = match &parsed {
+                            Ok(parsed) => timed(|| parts[*part as usize - 1](parsed)),
+                            Err(message) => (Err(message.clone()), Duration::ZERO),
+                        };
+
+                        PartRun { part: *part, answer, solve_time }
+                    })
+                    .collect();
+
+                PuzzleRun { parse_time, parts }
+            }),
+            frames: None,
+        }
+    }
+
+    /// Adds a way to play out the puzzle's simulation a frame at a time, for `aoc render`
+    pub fn with_frames(mut self, frames: Frames) -> Puzzle {
+        self.frames = Some(frames);
+        self
+    }
+
+    /// Solves the requested parts for the given input text, returning the answers in the same order. Parts are
+    /// numbered from 1. A part that panics is reported as an error rather than unwinding any further.
+    pub fn solve(&self, input: &str, parts: &[u8]) -> PuzzleRun {
+        assert!(parts.iter().all(|part| (1..=self.part_count).contains(part)), "Day {} has no such part", self.day);
+
+        (self.solver)(input, parts)
+    }
+
+    /// All the part numbers this puzzle has solutions for
+    pub fn parts(&self) -> Vec<u8> {
+        (1..=self.part_count).collect()
+    }
+}
+
+/// All the puzzles solved for a single Advent of Code year
+pub struct Year {
+    pub year: u16,
+    /// Directory holding the `dayN_input.txt` files for this year
+    pub input_dir: PathBuf,
+    /// Directory holding the `dayN_exampleM.txt` files for this year, which always live in the crate
+    pub examples_dir: PathBuf,
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl Year {
+    /// Sets up a year whose crate lives in the given directory, usually `env!("CARGO_MANIFEST_DIR")`
+    pub fn new(year: u16, crate_dir: &str, puzzles: Vec<Puzzle>) -> Year {
+        let crate_dir = Path::new(crate_dir);
+
+        Year {
+            year,
+            input_dir: input::input_dir(year, crate_dir),
+            examples_dir: input::crate_input_dir(crate_dir).join("examples"),
+            puzzles,
+        }
+    }
+
+    pub fn puzzle(&self, day: u8) -> Option<&Puzzle> {
+        self.puzzles.iter().find(|puzzle| puzzle.day == day)
+    }
+
+    /// Solves one part of a day for the given input text, which is how solutions can be used as a library:
+    ///
+    /// ```ignore
+    /// let answer = aoc_2024::year().solve(1, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n");
+    /// assert_eq!(answer, Ok("11".to_string()));
+    /// ```
+    pub fn solve(&self, day: u8, part: u8, input: &str) -> Result<String, String> {
+        let puzzle = self.puzzle(day).ok_or(format!("{} has no solution for day {}", self.year, day))?;
+        if part < 1 || part > puzzle.part_count {
+            return Err(format!("Day {} has no part {}", day, part))
+        }
+
+        puzzle.solve(input, &[part]).parts.remove(0).answer
+    }
+
+    /// The default location of the input for the given day
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.input_dir.join(format!("day{}_input.txt", day))
+    }
+
+    /// Reads the input for the given day from the default location
+    pub fn read_input(&self, day: u8) -> Result<String, String> {
+        input::read_input(&self.input_path(day))
+    }
+}
+
+/// Registers a day's parse function and part functions as a `Puzzle`. Each part function takes a reference to
+/// whatever the parse function returns, and can return any answer type that implements `Display`.
+///
+/// ```ignore
+/// puzzle!(1, "Historian Hysteria", day1_parse, day1_part1, day1_part2)
+/// ```
+#[macro_export]
+macro_rules! puzzle {
+    ($day:expr, $title:expr, $parse:expr, $($part:expr),+ $(,)?) => {
+        $crate::Puzzle::new($day, $title, $parse, vec![$(|input: &_| $part(input).to_string()),+])
+    };
+}
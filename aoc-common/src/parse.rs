@@ -0,0 +1,301 @@
+//! Turning puzzle input into typed values, with errors that point at the line and column that didn't parse.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// Where some input failed to parse and why. Lines and columns are numbered from 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError { line, column, message: message.into() }
+    }
+
+    /// Moves an error from a piece of the input to where that piece starts within the whole input
+    fn offset(self, line: usize, column: usize) -> ParseError {
+        let column = if self.line == 1 { column + self.column - 1 } else { self.column };
+
+        ParseError { line: line + self.line - 1, column, message: self.message }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Something that can be parsed from puzzle input, or from a piece of it
+pub trait FromInput: Sized {
+    fn from_input(input: &str) -> Result<Self, ParseError>;
+}
+
+impl<T: FromStr> FromInput for T where T::Err: fmt::Display {
+    fn from_input(input: &str) -> Result<T, ParseError> {
+        input.parse::<T>().map_err(|err| ParseError::new(1, 1, format!("{:?}: {}", input, err)))
+    }
+}
+
+/// Parses the input, panicking with the location of the problem if it's invalid. This suits parse functions
+/// registered with `puzzle!`, since the runner reports the panic message against the day.
+pub fn parse<T: FromInput>(input: &str) -> T {
+    T::from_input(input).unwrap_or_else(|err| panic!("Invalid input at {}", err))
+}
+
+/// The input without any blank lines at the end
+fn trim_trailing_lines(input: &str) -> &str {
+    input.trim_end_matches(['\n', '\r'])
+}
+
+/// Splits the text on the separator, trimming each field and keeping track of where it started
+fn fields<'a>(text: &'a str, separator: impl Fn(char) -> bool + 'a) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    let mut start = 0;
+
+    text.split(separator).map(move |field| {
+        let trimmed_start = field.len() - field.trim_start().len();
+        let result = (start + trimmed_start, field.trim());
+        // The separator can be more than one byte, such as a non-ASCII space
+        start += field.len() + text[start + field.len()..].chars().next().map_or(0, char::len_utf8);
+
+        result
+    })
+}
+
+/// The 1-based line and column of a byte offset into the input
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+/// A run of non-blank lines from input where records are separated by blank lines
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record<'a> {
+    /// The line the record starts on, numbered from 1
+    pub line: usize,
+    /// The record's lines, without line endings or trailing whitespace
+    pub lines: Vec<&'a str>,
+}
+
+impl Record<'_> {
+    /// The record's lines joined back together with `\n`
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// Splits input into records separated by blank lines. LF and CRLF line endings are both fine, whitespace at the end
+/// of a line is ignored, lines holding only whitespace count as blank, and any number of blank lines may come
+/// before, between or after the records. The last record doesn't need a blank line after it.
+pub fn records(input: &str) -> Vec<Record<'_>> {
+    let mut records = vec![];
+    let mut current: Option<Record> = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+
+        match (&mut current, line.is_empty()) {
+            (Some(record), false) => record.lines.push(line),
+            (None, false) => current = Some(Record { line: index + 1, lines: vec![line] }),
+            (Some(_), true) => records.extend(current.take()),
+            (None, true) => {},
+        }
+    }
+    records.extend(current);
+
+    records
+}
+
+/// One value per line
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lines<T>(pub Vec<T>);
+
+impl<T: FromInput> FromInput for Lines<T> {
+    fn from_input(input: &str) -> Result<Lines<T>, ParseError> {
+        trim_trailing_lines(input).lines()
+            .enumerate()
+            .map(|(index, line)| T::from_input(line).map_err(|err| err.offset(index + 1, 1)))
+            .collect::<Result<_, _>>()
+            .map(Lines)
+    }
+}
+
+/// Groups of lines separated by blank lines, with each group parsed as a whole. See `records` for how the groups
+/// are split up.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Groups<T>(pub Vec<T>);
+
+impl<T: FromInput> FromInput for Groups<T> {
+    fn from_input(input: &str) -> Result<Groups<T>, ParseError> {
+        records(input).iter()
+            .map(|record| T::from_input(&record.text()).map_err(|err| err.offset(record.line, 1)))
+            .collect::<Result<_, _>>()
+            .map(Groups)
+    }
+}
+
+/// Comma separated values, which may be spread over several lines. Empty fields are skipped, so a line ending in a
+/// comma is fine.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Csv<T>(pub Vec<T>);
+
+impl<T: FromInput> FromInput for Csv<T> {
+    fn from_input(input: &str) -> Result<Csv<T>, ParseError> {
+        fields(input, |c| c == ',' || c == '\n')
+            .filter(|(_, field)| !field.is_empty())
+            .map(|(offset, field)| {
+                let (line, column) = position(input, offset);
+                T::from_input(field).map_err(|err| err.offset(line, column))
+            })
+            .collect::<Result<_, _>>()
+            .map(Csv)
+    }
+}
+
+/// A pair of whitespace separated columns on every line
+#[derive(Clone, Debug, PartialEq)]
+pub struct Columns<T>(pub Vec<T>);
+
+impl<A, B> Columns<(A, B)> {
+    /// Splits the rows into one list per column
+    pub fn unzip(self) -> (Vec<A>, Vec<B>) {
+        self.0.into_iter().unzip()
+    }
+}
+
+impl<A: FromInput, B: FromInput> FromInput for Columns<(A, B)> {
+    fn from_input(input: &str) -> Result<Columns<(A, B)>, ParseError> {
+        trim_trailing_lines(input).lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let values: Vec<(usize, &str)> = fields(line, char::is_whitespace)
+                    .filter(|(_, field)| !field.is_empty())
+                    .collect();
+
+                match values[..] {
+                    [(a_offset, a), (b_offset, b)] => Ok((
+                        A::from_input(a).map_err(|err| err.offset(index + 1, position(line, a_offset).1))?,
+                        B::from_input(b).map_err(|err| err.offset(index + 1, position(line, b_offset).1))?,
+                    )),
+                    _ => Err(ParseError::new(index + 1, 1, format!("expected 2 columns, found {}", values.len()))),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Columns)
+    }
+}
+
+impl FromInput for Grid<char> {
+    fn from_input(input: &str) -> Result<Grid<char>, ParseError> {
+        let rows: Vec<Vec<char>> = trim_trailing_lines(input).lines().map(|line| line.chars().collect()).collect();
+
+        if let Some(width) = rows.first().map(Vec::len) {
+            if let Some(index) = rows.iter().position(|row| row.len() != width) {
+                let message = format!("expected {} characters like the first row, found {}", width, rows[index].len());
+                return Err(ParseError::new(index + 1, rows[index].len().min(width) + 1, message))
+            }
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, records, Columns, Csv, FromInput, Groups, Lines, ParseError};
+    use crate::grid::Grid;
+
+    fn record_lines(input: &str) -> Vec<Vec<&str>> {
+        records(input).into_iter().map(|record| record.lines).collect()
+    }
+
+    #[test]
+    fn test_records() {
+        let expected = vec![vec!["abc"], vec!["a", "b", "c"], vec!["b"]];
+
+        assert_eq!(record_lines("abc\n\na\nb\nc\n\nb\n"), expected);
+        assert_eq!(record_lines("abc\r\n\r\na\r\nb\r\nc\r\n\r\nb\r\n"), expected);
+        assert_eq!(record_lines("abc \n\t\na\nb  \nc\n \nb\t\n"), expected);
+        assert_eq!(record_lines("abc\n\na\nb\nc\n\nb"), expected);
+        assert_eq!(record_lines("abc\n\na\nb\nc\n\nb\n\n\n"), expected);
+        assert_eq!(record_lines("\n\nabc\n\n\n\na\nb\nc\n\nb\n"), expected);
+        assert!(records("").is_empty());
+        assert!(records("\n\r\n  \n").is_empty());
+    }
+
+    #[test]
+    fn test_record_lines_numbers() {
+        let starts: Vec<usize> = records("\nabc\n\na\nb\n\n\nb").iter().map(|record| record.line).collect();
+        assert_eq!(starts, vec![2, 4, 8]);
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(parse::<Lines<i32>>("1\n-2\r\n3\n\n"), Lines(vec![1, -2, 3]));
+
+        let err = Lines::<i32>::from_input("1\n2\nthree\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn test_groups() {
+        let Groups(groups) = parse::<Groups<Lines<String>>>("abc\n\na\nb\n\n\nab\n");
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[1], Lines(vec!["a".to_string(), "b".to_string()]));
+
+        let err = Groups::<Lines<u8>>::from_input("1\n2\n\n3\nx\n").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(parse::<Csv<i32>>("1,9,10,3\n"), Csv(vec![1, 9, 10, 3]));
+        assert_eq!(parse::<Csv<i32>>("1, 2,\n3\n"), Csv(vec![1, 2, 3]));
+
+        let err = Csv::<i32>::from_input("1,2,3\n4, x5,6\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn test_columns() {
+        let (left, right) = parse::<Columns<(i32, i32)>>("3   4\n4   3\n").unzip();
+        assert_eq!((left, right), (vec![3, 4], vec![4, 3]));
+
+        let err = Columns::<(i32, i32)>::from_input("3   4\n4   y\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err = Columns::<(i32, i32)>::from_input("3 4 5\n").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 1, "expected 2 columns, found 3"));
+
+        // Columns count characters rather than bytes
+        let err = Columns::<(String, i32)>::from_input("né   x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        let err = Columns::<(String, i32)>::from_input("héllo\u{3000}y\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = parse::<Grid<char>>("#.#\n..#\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+
+        let err = Grid::<char>::from_input("#.#\n.#\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid input at line 2, column 1")]
+    fn test_parse_panics_with_position() {
+        parse::<Lines<u32>>("1\n-1\n");
+    }
+}
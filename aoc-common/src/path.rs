@@ -0,0 +1,395 @@
+//! Shortest paths with breadth-first search, Dijkstra's algorithm and A*.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::grid::{Grid, Point};
+
+/// A route from the start to a goal, and what it cost
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// Every state along the way, starting with the start and ending with the goal
+    pub states: Vec<S>,
+}
+
+/// Every cheapest route from the start to the goals that can be reached most cheaply
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<S> {
+    pub cost: u64,
+    states: Vec<S>,
+    /// The indices of the states each state can be reached from most cheaply
+    parents: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// Each route, start first. There can be a very large number of them, so `states` is the better choice when only
+    /// the cells they cover matter.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = vec![];
+
+        for &goal in &self.goals {
+            let mut stack: Vec<Vec<usize>> = vec![vec![goal]];
+
+            while let Some(route) = stack.pop() {
+                let last = *route.last().unwrap();
+
+                if self.parents[last].is_empty() {
+                    paths.push(route.iter().rev().map(|&index| self.states[index].clone()).collect());
+                }
+                for &parent in &self.parents[last] {
+                    let mut longer = route.clone();
+                    longer.push(parent);
+                    stack.push(longer);
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Every state that's on at least one of the routes
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<usize> = self.goals.iter().copied().collect();
+        let mut pending: Vec<usize> = self.goals.clone();
+
+        while let Some(index) = pending.pop() {
+            for &parent in &self.parents[index] {
+                if seen.insert(parent) {
+                    pending.push(parent);
+                }
+            }
+        }
+
+        seen.into_iter().map(|index| self.states[index].clone()).collect()
+    }
+}
+
+/// The states a search has come across, each given an index so the rest of the search can use plain vectors
+struct Visited<S> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Visited<S> {
+        Visited { states: vec![], indices: HashMap::new() }
+    }
+
+    /// The state's index, and whether this is the first time it's been seen
+    fn index(&mut self, state: S) -> (usize, bool) {
+        match self.indices.get(&state) {
+            Some(&index) => (index, false),
+            None => {
+                self.indices.insert(state.clone(), self.states.len());
+                self.states.push(state);
+                (self.states.len() - 1, true)
+            },
+        }
+    }
+
+    /// Follows the parents back from the goal to the start
+    fn path(&self, parents: &[Option<usize>], goal: usize, cost: u64) -> Path<S> {
+        let mut indices = vec![goal];
+        while let Some(parent) = parents[*indices.last().unwrap()] {
+            indices.push(parent);
+        }
+
+        Path { cost, states: indices.iter().rev().map(|&index| self.states[index].clone()).collect() }
+    }
+}
+
+/// The fewest moves from the start to a goal, where every move costs 1
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S>>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = S> {
+    let mut visited = Visited::new();
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut queue: VecDeque<(usize, u64)> = VecDeque::from([(visited.index(start).0, 0)]);
+
+    while let Some((index, cost)) = queue.pop_front() {
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path(&parents, index, cost))
+        }
+
+        for next in successors(&visited.states[index].clone()) {
+            let (next, new) = visited.index(next);
+            if new {
+                parents.push(Some(index));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// The cheapest route from the start to a goal, where the successors come with the cost of moving to them
+pub fn dijkstra<S, I>(start: S, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<Path<S>>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, u64)> {
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// The cheapest route from the start to a goal, looking at the most promising states first. The heuristic guesses
+/// the cost from a state to the nearest goal, and mustn't ever guess too high or the route might not be the cheapest.
+pub fn astar<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut heuristic: impl FnMut(&S) -> u64,
+                   mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S>>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, u64)> {
+    let mut visited = Visited::new();
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut costs: Vec<u64> = vec![0];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, visited.index(start).0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            // A cheaper way here has already been dealt with
+            continue
+        }
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path(&parents, index, cost))
+        }
+
+        for (next, step) in successors(&visited.states[index].clone()) {
+            let next_cost = cost + step;
+            let (next, new) = visited.index(next);
+
+            if new {
+                parents.push(Some(index));
+                costs.push(next_cost);
+            } else if next_cost < costs[next] {
+                parents[next] = Some(index);
+                costs[next] = next_cost;
+            } else {
+                continue
+            }
+
+            heap.push(Reverse((next_cost + heuristic(&visited.states[next]), next_cost, next)));
+        }
+    }
+
+    None
+}
+
+/// Every cheapest route from the start to a goal, using Dijkstra's algorithm. Only the goals that can be reached for
+/// the lowest cost are included.
+pub fn dijkstra_all<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool)
+    -> Option<ShortestPaths<S>> where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, u64)> {
+    let mut visited = Visited::new();
+    let mut parents: Vec<Vec<usize>> = vec![vec![]];
+    let mut costs: Vec<u64> = vec![0];
+    let mut heap = BinaryHeap::from([Reverse((0, visited.index(start).0))]);
+    let mut goals: Vec<usize> = vec![];
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            continue
+        }
+        if goals.first().is_some_and(|&goal| cost > costs[goal]) {
+            // Everything left costs more than the goals already found
+            break
+        }
+        if is_goal(&visited.states[index]) {
+            goals.push(index);
+            continue
+        }
+
+        for (next, step) in successors(&visited.states[index].clone()) {
+            let next_cost = cost + step;
+            let (next, new) = visited.index(next);
+
+            if new {
+                parents.push(vec![index]);
+                costs.push(next_cost);
+            } else if next_cost < costs[next] {
+                parents[next] = vec![index];
+                costs[next] = next_cost;
+            } else {
+                if next_cost == costs[next] && !parents[next].contains(&index) {
+                    parents[next].push(index);
+                }
+                continue
+            }
+
+            heap.push(Reverse((next_cost, next)));
+        }
+    }
+
+    let cost = costs[*goals.first()?];
+    Some(ShortestPaths { cost, states: visited.states, parents, goals })
+}
+
+/// The cheapest cost of reaching every state that can be reached from the start
+pub fn distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, u64>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, u64)> {
+    let mut visited = Visited::new();
+    let mut costs: Vec<u64> = vec![0];
+    let mut heap = BinaryHeap::from([Reverse((0, visited.index(start).0))]);
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            continue
+        }
+
+        for (next, step) in successors(&visited.states[index].clone()) {
+            let next_cost = cost + step;
+            let (next, new) = visited.index(next);
+
+            if new {
+                costs.push(next_cost);
+            } else if next_cost < costs[next] {
+                costs[next] = next_cost;
+            } else {
+                continue
+            }
+
+            heap.push(Reverse((next_cost, next)));
+        }
+    }
+
+    visited.states.into_iter().zip(costs).collect()
+}
+
+/// The fewest steps between two cells of a grid, moving up, down, left and right through the cells the predicate
+/// allows. The start and goal are allowed whatever they hold.
+pub fn grid_path<T>(grid: &Grid<T>, start: Point, goal: Point, mut passable: impl FnMut(&T) -> bool)
+    -> Option<Path<Point>> {
+    astar(start,
+          |point| grid.neighbors4(*point).filter(|next| *next == goal || passable(&grid[*next])).map(|next| (next, 1))
+              .collect::<Vec<(Point, u64)>>(),
+          |point| point.manhattan_distance(goal) as u64,
+          |point| *point == goal)
+}
+
+/// A graph given as a list of edges, each with a cost
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    edges: HashMap<N, Vec<(N, u64)>>,
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Graph<N> {
+        Graph { edges: HashMap::new() }
+    }
+
+    /// Adds a one-way edge
+    pub fn add_edge(&mut self, from: N, to: N, cost: u64) {
+        self.edges.entry(from).or_default().push((to, cost));
+    }
+
+    /// Adds an edge that can be taken either way for the same cost
+    pub fn add_undirected_edge(&mut self, a: N, b: N, cost: u64) {
+        self.add_edge(a.clone(), b.clone(), cost);
+        self.add_edge(b, a, cost);
+    }
+
+    /// The nodes that can be reached directly from the node, and the cost of getting to each one
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = (N, u64)> + '_ {
+        self.edges.get(node).into_iter().flatten().cloned()
+    }
+
+    /// The cheapest route between two nodes
+    pub fn shortest_path(&self, from: N, to: &N) -> Option<Path<N>> {
+        dijkstra(from, |node| self.successors(node), |node| node == to)
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Graph<N> {
+        Graph::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{astar, bfs, dijkstra, dijkstra_all, distances, grid_path, Graph};
+    use crate::grid::{Direction, Grid, Point};
+    use crate::parse::parse;
+
+    #[test]
+    fn test_grid_path() {
+        let maze: Grid<char> = parse("S.#.\n..#.\n#...\n..#E\n");
+        let (start, end) = (maze.find(&'S').unwrap(), maze.find(&'E').unwrap());
+
+        let path = grid_path(&maze, start, end, |cell| *cell != '#').unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.len(), 7);
+        assert_eq!((path.states[0], path.states[6]), (start, end));
+
+        assert!(grid_path(&maze, start, end, |cell| *cell == 'S').is_none());
+    }
+
+    #[test]
+    fn test_bfs() {
+        // Doubling or adding one, the quickest way from 1 to 10 is 1, 2, 4, 5, 10
+        let path = bfs(1, |&n| [n * 2, n + 1].into_iter().filter(|&next| next <= 10), |&n| n == 10).unwrap();
+
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states, vec![1, 2, 4, 5, 10]);
+        assert!(bfs(1, |&n| [n * 2].into_iter().filter(|&next| next <= 10), |&n| n == 10).is_none());
+    }
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::new();
+        graph.add_undirected_edge("a", "b", 7);
+        graph.add_undirected_edge("a", "c", 2);
+        graph.add_undirected_edge("c", "b", 3);
+        graph.add_edge("b", "d", 1);
+
+        let path = graph.shortest_path("a", &"d").unwrap();
+        assert_eq!((path.cost, path.states), (6, vec!["a", "c", "b", "d"]));
+        assert!(graph.shortest_path("d", &"a").is_none());
+        assert_eq!(distances("a", |node| graph.successors(node))[&"b"], 5);
+    }
+
+    /// Where a reindeer is and which way it's facing
+    type Reindeer = (Point, Direction);
+
+    /// Moving forward costs 1 and turning costs 1000, so the state is a position and the way it's facing
+    fn reindeer_successors(maze: &Grid<char>) -> impl Fn(&Reindeer) -> Vec<(Reindeer, u64)> + '_ {
+        |&(point, direction)| {
+            let mut next = vec![((point, direction.turn_left()), 1000), ((point, direction.turn_right()), 1000)];
+            if maze.get(point.step(direction)).is_some_and(|cell| *cell != '#') {
+                next.push(((point.step(direction), direction), 1));
+            }
+
+            next
+        }
+    }
+
+    #[test]
+    fn test_position_and_direction() {
+        let maze: Grid<char> = parse("S.#.\n..#.\n#...\n..#E\n");
+        let (start, end) = (maze.find(&'S').unwrap(), maze.find(&'E').unwrap());
+
+        let path = dijkstra((start, Direction::East), reindeer_successors(&maze), |(point, _)| *point == end).unwrap();
+        assert_eq!(path.cost, 3006);
+
+        let guided = astar((start, Direction::East), reindeer_successors(&maze),
+                           |(point, _)| point.manhattan_distance(end) as u64, |(point, _)| *point == end).unwrap();
+        assert_eq!(guided.cost, path.cost);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let open: Grid<char> = parse("...\n...\n");
+        let goal = Point::new(2, 1);
+
+        let shortest = dijkstra_all(Point::ORIGIN,
+                                    |point| open.neighbors4(*point).map(|next| (next, 1)).collect::<Vec<_>>(),
+                                    |point| *point == goal).unwrap();
+        assert_eq!(shortest.cost, 3);
+        assert_eq!(shortest.paths().len(), 3);
+        assert_eq!(shortest.states(), open.points().collect::<HashSet<Point>>());
+
+        let maze: Grid<char> = parse("S.#.\n..#.\n#...\n..#E\n");
+        let (start, end) = (maze.find(&'S').unwrap(), maze.find(&'E').unwrap());
+        let shortest = dijkstra_all((start, Direction::East), reindeer_successors(&maze), |(point, _)| *point == end)
+            .unwrap();
+        assert_eq!(shortest.cost, 3006);
+        assert!(shortest.paths().iter().all(|path| path.last().unwrap().0 == end));
+    }
+}
@@ -0,0 +1,202 @@
+//! Flood fill and connected regions of a grid.
+
+use std::collections::VecDeque;
+
+use crate::grid::{Direction, Grid, Point};
+
+/// Which neighbours join cells into the same region
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right
+    Four,
+    /// Diagonals as well
+    Eight,
+}
+
+impl Connectivity {
+    fn directions(self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => &Direction::CARDINAL,
+            Connectivity::Eight => &Direction::ALL,
+        }
+    }
+}
+
+/// A connected group of cells
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region {
+    /// The region's cells in reading order
+    pub cells: Vec<Point>,
+}
+
+impl Region {
+    fn new(mut cells: Vec<Point>) -> Region {
+        cells.sort();
+        Region { cells }
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.binary_search(&point).is_ok()
+    }
+
+    /// How many cell sides face a cell outside the region, counting the sides along the edge of the grid
+    pub fn perimeter(&self) -> usize {
+        self.cells.iter()
+            .map(|cell| Direction::CARDINAL.iter().filter(|direction| !self.contains(cell.step(**direction))).count())
+            .sum()
+    }
+
+    /// How many straight runs of fence it takes to go around the region, and around any holes in it. This is the same
+    /// as the number of corners, where a corner is either two outside sides of a cell meeting, or two cells of the
+    /// region meeting around the outside of a third.
+    pub fn sides(&self) -> usize {
+        self.cells.iter()
+            .map(|cell| {
+                Direction::CARDINAL.iter()
+                    .filter(|direction| {
+                        let (ahead, right) = (cell.step(**direction), cell.step(direction.turn_right()));
+                        let diagonal = ahead.step(direction.turn_right());
+
+                        match (self.contains(ahead), self.contains(right)) {
+                            (false, false) => true,
+                            (true, true) => !self.contains(diagonal),
+                            _ => false,
+                        }
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The cells just outside the region, in reading order, that are neighbours of one of its cells. These can be off
+    /// the edge of the grid.
+    pub fn adjacent(&self, connectivity: Connectivity) -> Vec<Point> {
+        let mut adjacent: Vec<Point> = self.cells.iter()
+            .flat_map(|cell| connectivity.directions().iter().map(move |direction| cell.step(*direction)))
+            .filter(|point| !self.contains(*point))
+            .collect();
+
+        adjacent.sort();
+        adjacent.dedup();
+        adjacent
+    }
+}
+
+/// The region of cells the predicate picks out that can be reached from the start. It's empty if the predicate
+/// doesn't pick out the start.
+pub fn flood_fill<T>(grid: &Grid<T>, start: Point, connectivity: Connectivity, mut include: impl FnMut(&T) -> bool)
+    -> Region {
+    let mut seen = grid.map(|_| false);
+
+    match grid.get(start) {
+        Some(cell) if include(cell) => fill(grid, start, connectivity, &mut seen, |_, next| include(next)),
+        _ => Region::new(vec![]),
+    }
+}
+
+/// The regions made by joining neighbouring cells the predicate picks out, in reading order of their first cells
+pub fn components<T>(grid: &Grid<T>, connectivity: Connectivity, mut include: impl FnMut(&T) -> bool) -> Vec<Region> {
+    let mut seen = grid.map(|_| false);
+    let mut regions = vec![];
+
+    for (point, cell) in grid.iter() {
+        if !seen[point] && include(cell) {
+            regions.push(fill(grid, point, connectivity, &mut seen, |_, next| include(next)));
+        }
+    }
+
+    regions
+}
+
+/// The regions made by joining neighbouring cells that hold the same value, covering the whole grid, in reading order
+/// of their first cells
+pub fn regions<T: PartialEq>(grid: &Grid<T>, connectivity: Connectivity) -> Vec<Region> {
+    let mut seen = grid.map(|_| false);
+    let mut regions = vec![];
+
+    for point in grid.points() {
+        if !seen[point] {
+            regions.push(fill(grid, point, connectivity, &mut seen, |cell, next| cell == next));
+        }
+    }
+
+    regions
+}
+
+/// Spreads out from the start to every unseen neighbour the join function accepts, given the cell it's spreading from
+/// and the one it's spreading to, marking each one as seen
+fn fill<T>(grid: &Grid<T>, start: Point, connectivity: Connectivity, seen: &mut Grid<bool>,
+           mut join: impl FnMut(&T, &T) -> bool) -> Region {
+    let mut cells = vec![start];
+    let mut pending = VecDeque::from([start]);
+    seen[start] = true;
+
+    while let Some(point) = pending.pop_front() {
+        for direction in connectivity.directions() {
+            let next = point.step(*direction);
+
+            if grid.in_bounds(next) && !seen[next] && join(&grid[point], &grid[next]) {
+                seen[next] = true;
+                cells.push(next);
+                pending.push_back(next);
+            }
+        }
+    }
+
+    Region::new(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{components, flood_fill, regions, Connectivity, Region};
+    use crate::grid::{Grid, Point};
+    use crate::parse::parse;
+
+    /// The total fencing price of a garden, as area times perimeter and as area times sides
+    fn prices(garden: &str) -> (usize, usize) {
+        let plots = regions(&parse::<Grid<char>>(garden), Connectivity::Four);
+
+        (plots.iter().map(|plot| plot.area() * plot.perimeter()).sum(),
+         plots.iter().map(|plot| plot.area() * plot.sides()).sum())
+    }
+
+    #[test]
+    fn test_regions() {
+        let garden: Grid<char> = parse("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let plots = regions(&garden, Connectivity::Four);
+
+        let summary: Vec<(char, usize, usize, usize)> = plots.iter()
+            .map(|plot| (garden[plot.cells[0]], plot.area(), plot.perimeter(), plot.sides()))
+            .collect();
+        assert_eq!(summary, vec![('A', 4, 10, 4), ('B', 4, 8, 4), ('C', 4, 10, 8), ('D', 1, 4, 4), ('E', 3, 8, 4)]);
+
+        assert_eq!(prices("AAAA\nBBCD\nBBCC\nEEEC\n"), (140, 80));
+        assert_eq!(prices("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n").1, 236);
+        assert_eq!(prices("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n").1, 368);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid: Grid<char> = parse("#..#\n.#..\n...#\n");
+
+        assert_eq!(components(&grid, Connectivity::Four, |cell| *cell == '#').len(), 4);
+        let diagonal = components(&grid, Connectivity::Eight, |cell| *cell == '#');
+        assert_eq!(diagonal.len(), 3);
+        assert_eq!(diagonal[0], Region { cells: vec![Point::new(0, 0), Point::new(1, 1)] });
+        assert_eq!(diagonal[0].adjacent(Connectivity::Four).len(), 6);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid: Grid<char> = parse("..#.\n.##.\n#...\n");
+
+        assert_eq!(flood_fill(&grid, Point::ORIGIN, Connectivity::Four, |cell| *cell == '.').area(), 3);
+        assert_eq!(flood_fill(&grid, Point::new(3, 0), Connectivity::Four, |cell| *cell == '.').area(), 5);
+        assert_eq!(flood_fill(&grid, Point::ORIGIN, Connectivity::Eight, |cell| *cell == '.').area(), 8);
+        assert_eq!(flood_fill(&grid, Point::new(2, 0), Connectivity::Four, |cell| *cell == '.').area(), 0);
+    }
+}
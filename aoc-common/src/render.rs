@@ -0,0 +1,252 @@
+//! Drawing grids as PPM or PNG images, and simulations as PNG frames or an animated GIF.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::grid::{Grid, Point};
+
+/// A red, green and blue colour
+pub type Colour = [u8; 3];
+
+/// The colours used for the characters puzzle maps are usually drawn with. Anything else gets a colour worked out
+/// from the character, so different characters still look different.
+pub fn char_colour(c: char) -> Colour {
+    match c {
+        '.' => [24, 24, 32],
+        '#' => [200, 200, 210],
+        'L' => [60, 160, 90],
+        'X' | 'O' => [240, 170, 40],
+        '^' | '>' | 'v' | '<' => [230, 50, 50],
+        '0'..='9' => [90, 150, 240],
+        _ => {
+            let hash = (c as u32).wrapping_mul(2_654_435_761);
+            [64 + (hash >> 24) as u8 % 192, 64 + (hash >> 16) as u8 % 192, 64 + (hash >> 8) as u8 % 192]
+        },
+    }
+}
+
+/// An image made of RGB pixels
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Image {
+    /// Draws the grid with each cell as a `scale` by `scale` square in the colour the function gives it
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, colour: impl Fn(&T) -> Colour) -> Image {
+        let scale = scale.max(1);
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new((x / scale) as i64, (y / scale) as i64)))
+            .map(|point| colour(&grid[point]))
+            .collect();
+
+        Image { width, height, pixels }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The image as a binary PPM file
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().flatten());
+
+        data
+    }
+
+    /// The image as a PNG file
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut data = vec![];
+
+        let mut encoder = png::Encoder::new(&mut data, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(|err| format!("Unable to encode PNG: {}", err))?;
+        writer.write_image_data(&self.pixels.concat()).map_err(|err| format!("Unable to encode PNG: {}", err))?;
+        writer.finish().map_err(|err| format!("Unable to encode PNG: {}", err))?;
+
+        Ok(data)
+    }
+
+    /// Saves the image as PNG or PPM, depending on the path's extension
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let data = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => self.to_png()?,
+            Some("ppm") => self.to_ppm(),
+            _ => return Err(format!("Images can be saved as .png or .ppm, not {}", path.display())),
+        };
+
+        fs::write(path, data).map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+    }
+
+    /// The image's distinct colours and each pixel's index among them, as a GIF frame needs
+    fn palette(&self) -> Result<(Vec<u8>, Vec<u8>), String> {
+        let mut indices: HashMap<Colour, u8> = HashMap::new();
+        let mut palette = vec![];
+
+        let pixels = self.pixels.iter()
+            .map(|colour| match indices.get(colour) {
+                Some(index) => Ok(*index),
+                None if indices.len() < 256 => {
+                    let index = indices.len() as u8;
+                    indices.insert(*colour, index);
+                    palette.extend(colour);
+                    Ok(index)
+                },
+                None => Err("A GIF frame can't have more than 256 colours".to_string()),
+            })
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        Ok((palette, pixels))
+    }
+}
+
+/// Writes frames to an animated GIF as they're added. Every frame has to be the same size.
+pub struct GifWriter {
+    encoder: gif::Encoder<BufWriter<File>>,
+    path: PathBuf,
+    width: usize,
+    height: usize,
+    /// How long each frame is shown, in hundredths of a second
+    delay: u16,
+}
+
+impl GifWriter {
+    /// Starts a GIF that loops forever, showing each frame for the given time
+    pub fn create(path: &Path, width: usize, height: usize, delay: Duration) -> Result<GifWriter, String> {
+        let error = |err: gif::EncodingError| format!("Unable to write {}: {}", path.display(), err);
+
+        if width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err(format!("{}x{} is too big for a GIF", width, height))
+        }
+        let file = File::create(path).map_err(|err| format!("Unable to create {}: {}", path.display(), err))?;
+
+        let mut encoder = gif::Encoder::new(BufWriter::new(file), width as u16, height as u16, &[]).map_err(error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(error)?;
+
+        let delay = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+
+        Ok(GifWriter { encoder, path: path.to_path_buf(), width, height, delay })
+    }
+
+    pub fn add(&mut self, image: &Image) -> Result<(), String> {
+        if (image.width, image.height) != (self.width, self.height) {
+            return Err(format!("Every frame should be {}x{}, not {}x{}", self.width, self.height, image.width,
+                               image.height))
+        }
+
+        let (palette, pixels) = image.palette()?;
+        let mut frame = gif::Frame::from_palette_pixels(self.width as u16, self.height as u16, pixels, palette, None);
+        frame.delay = self.delay;
+
+        self.encoder.write_frame(&frame).map_err(|err| format!("Unable to write {}: {}", self.path.display(), err))
+    }
+}
+
+/// Writes frames to a directory as numbered PNG files: `frame00001.png`, `frame00002.png` and so on
+pub struct PngSequence {
+    dir: PathBuf,
+    count: usize,
+}
+
+impl PngSequence {
+    pub fn create(dir: &Path) -> Result<PngSequence, String> {
+        fs::create_dir_all(dir).map_err(|err| format!("Unable to create {}: {}", dir.display(), err))?;
+
+        Ok(PngSequence { dir: dir.to_path_buf(), count: 0 })
+    }
+
+    pub fn add(&mut self, image: &Image) -> Result<(), String> {
+        self.count += 1;
+        image.save(&self.dir.join(format!("frame{:05}.png", self.count)))
+    }
+
+    /// How many frames have been written
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{char_colour, GifWriter, Image, PngSequence};
+    use crate::grid::Grid;
+    use crate::parse::parse;
+
+    fn image() -> Image {
+        Image::from_grid(&parse::<Grid<char>>("#.\n.#\n"), 2, |cell| char_colour(*cell))
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let image = image();
+        let (wall, floor) = (char_colour('#'), char_colour('.'));
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.pixels[..4], [wall, wall, floor, floor]);
+        assert_eq!(image.pixels[12..], [floor, floor, wall, wall]);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = image().to_ppm();
+
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), "P6\n4 4\n255\n".len() + 4 * 4 * 3);
+    }
+
+    #[test]
+    fn test_png() {
+        let png = image().to_png().unwrap();
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert_eq!(&png[16..24], &[0, 0, 0, 4, 0, 0, 0, 4]);
+    }
+
+    #[test]
+    fn test_palette() {
+        let (palette, pixels) = image().palette().unwrap();
+
+        assert_eq!(palette.len(), 6);
+        assert_eq!(pixels[..4], [0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_gif_and_sequence() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut gif = GifWriter::create(&dir.join("anim.gif"), 4, 4, std::time::Duration::from_millis(100)).unwrap();
+        gif.add(&image()).unwrap();
+        assert!(gif.add(&Image::from_grid(&Grid::new(1, 1, '.'), 1, |cell| char_colour(*cell))).is_err());
+        drop(gif);
+        assert!(fs::read(dir.join("anim.gif")).unwrap().starts_with(b"GIF89a"));
+
+        let mut frames = PngSequence::create(&dir.join("frames")).unwrap();
+        frames.add(&image()).unwrap();
+        frames.add(&image()).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert!(dir.join("frames").join("frame00002.png").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+//! Numbers written into grids of characters, like the part numbers around an engine schematic.
+
+use std::ops::Range;
+
+use crate::grid::{Grid, Point};
+
+/// A run of digits along one row of a grid, and the number they make
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberSpan {
+    pub value: u64,
+    pub row: i64,
+    /// The columns the digits are in
+    pub columns: Range<i64>,
+}
+
+impl NumberSpan {
+    /// The cells holding the digits, left to right
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.columns.clone().map(|column| Point::new(column, self.row))
+    }
+
+    /// Whether the point is next to one of the digits, including diagonally. A digit's own cell doesn't count.
+    pub fn touches(&self, point: Point) -> bool {
+        (self.row - 1..=self.row + 1).contains(&point.y)
+            && (self.columns.start - 1..=self.columns.end).contains(&point.x)
+            && !(point.y == self.row && self.columns.contains(&point.x))
+    }
+
+    /// The cells on the grid that are next to one of the digits, in reading order
+    pub fn neighbours<T>(&self, grid: &Grid<T>) -> Vec<Point> {
+        (self.row - 1..=self.row + 1)
+            .flat_map(|y| (self.columns.start - 1..=self.columns.end).map(move |x| Point::new(x, y)))
+            .filter(|point| grid.in_bounds(*point) && self.touches(*point))
+            .collect()
+    }
+
+    /// The neighbouring cells holding a symbol, which is whatever the predicate says it is, in reading order
+    pub fn symbols(&self, grid: &Grid<char>, mut is_symbol: impl FnMut(char) -> bool) -> Vec<(Point, char)> {
+        self.neighbours(grid).into_iter()
+            .map(|point| (point, grid[point]))
+            .filter(|(_, cell)| is_symbol(*cell))
+            .collect()
+    }
+}
+
+/// Every number written along the rows of the grid, in reading order. A number ends at the end of its row, so digits
+/// at the end of one row and the start of the next are two numbers. Numbers too big for a `u64` are left out.
+pub fn number_spans(grid: &Grid<char>) -> Vec<NumberSpan> {
+    let mut spans = vec![];
+
+    for (row, cells) in grid.rows().enumerate() {
+        let mut column = 0;
+
+        while column < cells.len() {
+            if !cells[column].is_ascii_digit() {
+                column += 1;
+                continue
+            }
+
+            let start = column;
+            while column < cells.len() && cells[column].is_ascii_digit() {
+                column += 1;
+            }
+
+            let value = cells[start..column].iter()
+                .try_fold(0u64, |value, digit| value.checked_mul(10)?.checked_add(digit.to_digit(10).unwrap() as u64));
+            if let Some(value) = value {
+                spans.push(NumberSpan { value, row: row as i64, columns: start as i64..column as i64 });
+            }
+        }
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number_spans, NumberSpan};
+    use crate::grid::{Grid, Point};
+    use crate::parse::parse;
+
+    #[test]
+    fn test_number_spans() {
+        let grid: Grid<char> = parse("12..5\n..*..\n7.#34\n");
+        let spans = number_spans(&grid);
+
+        assert_eq!(spans, vec![
+            NumberSpan { value: 12, row: 0, columns: 0..2 },
+            NumberSpan { value: 5, row: 0, columns: 4..5 },
+            NumberSpan { value: 7, row: 2, columns: 0..1 },
+            NumberSpan { value: 34, row: 2, columns: 3..5 },
+        ]);
+        assert_eq!(spans[0].points().collect::<Vec<Point>>(), vec![Point::new(0, 0), Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn test_oversized_numbers() {
+        let grid: Grid<char> = parse("18446744073709551615.18446744073709551616\n");
+
+        assert_eq!(number_spans(&grid), vec![NumberSpan { value: u64::MAX, row: 0, columns: 0..20 }]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<char> = parse("12..5\n..*..\n7.#34\n");
+        let spans = number_spans(&grid);
+
+        assert_eq!(spans[0].neighbours(&grid),
+                   vec![Point::new(2, 0), Point::new(0, 1), Point::new(1, 1), Point::new(2, 1)]);
+        assert!(spans[0].touches(Point::new(2, 1)));
+        assert!(!spans[0].touches(Point::new(1, 0)));
+        assert!(!spans[0].touches(Point::new(3, 1)));
+
+        let is_symbol = |cell: char| cell != '.' && !cell.is_ascii_digit();
+        assert_eq!(spans[0].symbols(&grid, is_symbol), vec![(Point::new(2, 1), '*')]);
+        assert_eq!(spans[2].symbols(&grid, is_symbol), vec![]);
+        assert_eq!(spans[3].symbols(&grid, is_symbol), vec![(Point::new(2, 1), '*'), (Point::new(2, 2), '#')]);
+    }
+}
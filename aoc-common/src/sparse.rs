@@ -0,0 +1,235 @@
+//! A grid without edges that only stores the cells that have been set, for puzzles on an endless plane.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::grid::{Grid, Point};
+
+/// Cells at any signed position, stored only once they've been set
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    /// Kept in reading order, so iterating over the cells needs no sorting
+    cells: BTreeMap<Point, T>,
+    default: T,
+    /// The top left and bottom right corners of the box around the set cells
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty grid, where every cell reads as the default until it's set
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid { cells: BTreeMap::new(), default, bounds: None }
+    }
+
+    /// How many cells have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top left and bottom right corners of the smallest box holding every set cell, or `None` if nothing is set
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// The width of the box around the set cells
+    pub fn width(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// The height of the box around the set cells
+    pub fn height(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Whether the point is inside the box around the set cells
+    pub fn in_bounds(&self, point: Point) -> bool {
+        self.bounds.is_some_and(|(min, max)| (min.x..=max.x).contains(&point.x) && (min.y..=max.y).contains(&point.y))
+    }
+
+    /// Whether the cell has been set
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// The cell's value, or `None` if it hasn't been set. Indexing gives the default instead.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the cell, returning what it was set to before
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (Point::new(min.x.min(point.x), min.y.min(point.y)),
+                                 Point::new(max.x.max(point.x), max.y.max(point.y))),
+            None => (point, point),
+        });
+
+        self.cells.insert(point, value)
+    }
+
+    /// Clears the cell back to the default, shrinking the bounds if it was on their edge
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+
+        if let Some((min, max)) = self.bounds {
+            if point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y {
+                self.bounds = self.cells.keys().fold(None, |bounds, point| Some(match bounds {
+                    Some((min, max)) => (Point::new(min.x.min(point.x), min.y.min(point.y)),
+                                         Point::new(max.x.max(point.x), max.y.max(point.y))),
+                    None => (*point, *point),
+                }));
+            }
+        }
+
+        Some(value)
+    }
+
+    /// The set cells' points in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The set cells and their points in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// The points of the set cells that match the predicate, in reading order
+    pub fn positions(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Point> {
+        self.iter().filter(|(_, cell)| predicate(cell)).map(|(point, _)| point).collect()
+    }
+
+    pub fn find_all(&self, value: &T) -> Vec<Point> where T: PartialEq {
+        self.positions(|cell| cell == value)
+    }
+
+    /// The first set cell in reading order holding the value
+    pub fn find(&self, value: &T) -> Option<Point> where T: PartialEq {
+        self.iter().find(|(_, cell)| *cell == value).map(|(point, _)| point)
+    }
+
+    /// How many set cells hold the value
+    pub fn count(&self, value: &T) -> usize where T: PartialEq {
+        self.cells.values().filter(|cell| *cell == value).count()
+    }
+
+    /// A grid of the same cells, each changed by the function, with the default changed to match
+    pub fn map<U>(&self, mut cell: impl FnMut(&T) -> U) -> SparseGrid<U> {
+        SparseGrid {
+            cells: self.cells.iter().map(|(point, value)| (*point, cell(value))).collect(),
+            default: cell(&self.default),
+            bounds: self.bounds,
+        }
+    }
+
+    /// The box around the set cells as a dense grid, with the default in the cells that aren't set. Its top left
+    /// cell is the top left corner of the bounds.
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        let origin = self.bounds.map_or(Point::ORIGIN, |(min, _)| min);
+
+        Grid::from_fn(self.width(), self.height(), |point| self[origin + point].clone())
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    /// The cell's value, or the default if it hasn't been set
+    fn index(&self, point: Point) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+}
+
+impl<T: Clone> IndexMut<Point> for SparseGrid<T> {
+    /// Sets the cell to the default first if it hasn't been set
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        if !self.cells.contains_key(&point) {
+            self.insert(point, self.default.clone());
+        }
+
+        self.cells.get_mut(&point).unwrap()
+    }
+}
+
+/// Draws the box around the set cells, one row per line
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((min, max)) = self.bounds {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    write!(f, "{}", self[Point::new(x, y)])?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGrid;
+    use crate::grid::{Direction, Point};
+
+    #[test]
+    fn test_cells() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(Point::new(2, -1), '#');
+        grid.insert(Point::new(-1, 1), '#');
+        grid.insert(Point::new(0, 0), 'O');
+
+        assert_eq!(grid.get(Point::new(2, -1)), Some(&'#'));
+        assert_eq!(grid.get(Point::new(5, 5)), None);
+        assert_eq!(grid[Point::new(5, 5)], '.');
+        assert_eq!(grid.len(), 3);
+
+        grid[Point::ORIGIN.step(Direction::East)] = 'X';
+        assert_eq!(grid.len(), 4);
+        assert_eq!(grid.points().collect::<Vec<Point>>(),
+                   vec![Point::new(2, -1), Point::new(0, 0), Point::new(1, 0), Point::new(-1, 1)]);
+        assert_eq!(grid.find_all(&'#'), vec![Point::new(2, -1), Point::new(-1, 1)]);
+        assert_eq!(grid.count(&'#'), 2);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(Point::new(2, -1), '#');
+        grid.insert(Point::new(-1, 1), '#');
+        grid.insert(Point::new(0, 0), 'O');
+
+        assert_eq!(grid.bounds(), Some((Point::new(-1, -1), Point::new(2, 1))));
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert!(grid.in_bounds(Point::new(1, 1)));
+        assert!(!grid.in_bounds(Point::new(3, 0)));
+
+        assert_eq!(grid.remove(Point::new(2, -1)), Some('#'));
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(0, 1))));
+        grid.remove(Point::new(0, 0));
+        grid.remove(Point::new(-1, 1));
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.width(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(Point::new(2, -1), '#');
+        grid.insert(Point::new(-1, 1), '#');
+        grid.insert(Point::new(0, 0), 'O');
+
+        assert_eq!(grid.to_string(), "...#\n.O..\n#...\n");
+        assert_eq!(grid.to_grid().to_string(), grid.to_string());
+        assert_eq!(SparseGrid::new('.').to_string(), "");
+    }
+}
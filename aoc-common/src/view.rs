@@ -0,0 +1,270 @@
+//! Watching a grid simulation play out in the terminal.
+
+use std::io::{self, Stdout, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+
+use crate::grid::{Grid, Point};
+
+const MAX_FPS: u32 = 1000;
+
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+const HIGHLIGHT: &str = "\x1b[30;43m";
+const RESET: &str = "\x1b[0m";
+const CLEAR_TO_END_OF_LINE: &str = "\x1b[K";
+
+/// What a key press asks the viewer to do
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+    Nothing,
+}
+
+impl Action {
+    fn from_key(key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+            KeyCode::Char(' ') | KeyCode::Char('p') => Action::TogglePause,
+            KeyCode::Char('n') | KeyCode::Char('.') | KeyCode::Right => Action::Step,
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Action::Faster,
+            KeyCode::Char('-') | KeyCode::Down => Action::Slower,
+            KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+            _ => Action::Nothing,
+        }
+    }
+}
+
+/// Whether the animation is running, and how fast
+#[derive(Clone, Debug, PartialEq)]
+struct Controls {
+    fps: u32,
+    paused: bool,
+    /// Show one more frame, then stay paused
+    step: bool,
+    quit: bool,
+}
+
+impl Controls {
+    fn new(fps: u32) -> Controls {
+        Controls { fps: fps.clamp(1, MAX_FPS), paused: false, step: false, quit: false }
+    }
+
+    fn apply(&mut self, action: Action) {
+        match action {
+            Action::TogglePause => self.paused = !self.paused,
+            // Stepping while the animation runs pauses it, so the next frame can be looked at
+            Action::Step if !self.paused => self.paused = true,
+            Action::Step => self.step = true,
+            Action::Faster => self.fps = (self.fps * 2).min(MAX_FPS),
+            Action::Slower => self.fps = (self.fps / 2).max(1),
+            Action::Quit => self.quit = true,
+            Action::Nothing => {},
+        }
+    }
+
+    fn frame_time(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+}
+
+/// The grid as lines of text for a terminal in raw mode, with the cells that differ from the previous frame
+/// highlighted. Nothing is highlighted when there's no previous frame or it was a different size.
+fn draw(grid: &Grid<char>, previous: Option<&Grid<char>>) -> String {
+    let previous = previous.filter(|previous| (previous.width(), previous.height()) == (grid.width(), grid.height()));
+    let mut text = String::new();
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut highlighted = false;
+
+        for (x, cell) in row.iter().enumerate() {
+            let changed = previous.is_some_and(|previous| previous[Point::new(x as i64, y as i64)] != *cell);
+            if changed != highlighted {
+                text.push_str(if changed { HIGHLIGHT } else { RESET });
+                highlighted = changed;
+            }
+
+            text.push(*cell);
+        }
+
+        if highlighted {
+            text.push_str(RESET);
+        }
+        text.push_str(CLEAR_TO_END_OF_LINE);
+        text.push_str("\r\n");
+    }
+
+    text
+}
+
+/// Takes over the terminal to show frames as they arrive, until it's dropped
+pub struct Viewer {
+    stdout: Stdout,
+    controls: Controls,
+    previous: Option<Grid<char>>,
+    frame: usize,
+    finished: bool,
+}
+
+impl Viewer {
+    /// Switches the terminal to the alternate screen and starts reading keys, showing up to `fps` frames a second
+    pub fn start(fps: u32) -> Result<Viewer, String> {
+        terminal::enable_raw_mode().map_err(|err| format!("Unable to take over the terminal: {}", err))?;
+
+        let mut viewer = Viewer { stdout: io::stdout(), controls: Controls::new(fps), previous: None, frame: 0,
+                                  finished: false };
+        viewer.write(ENTER_ALTERNATE_SCREEN)?;
+
+        Ok(viewer)
+    }
+
+    /// Whether the user has stopped watching. Any frames after that are ignored.
+    pub fn quit(&self) -> bool {
+        self.controls.quit
+    }
+
+    /// Draws the next frame, then handles keys until it's time for the one after
+    pub fn show(&mut self, grid: &Grid<char>) -> Result<(), String> {
+        if self.controls.quit {
+            return Ok(())
+        }
+
+        self.frame += 1;
+        let frame = format!("\x1b[H{}", draw(grid, self.previous.as_ref()));
+        self.write(&frame)?;
+        self.write_status(grid.height())?;
+        self.previous = Some(grid.clone());
+
+        let drawn = Instant::now();
+        self.controls.step = false;
+
+        while !self.controls.quit && !self.controls.step {
+            let timeout = match self.controls.paused {
+                true => None,
+                false => match (drawn + self.controls.frame_time()).checked_duration_since(Instant::now()) {
+                    Some(timeout) => Some(timeout),
+                    None => break,
+                },
+            };
+
+            if self.read_key(timeout)? {
+                self.write_status(grid.height())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keeps the last frame on screen until the user quits
+    pub fn finish(mut self) -> Result<(), String> {
+        self.finished = true;
+        self.controls.paused = true;
+        let height = self.previous.as_ref().map_or(0, |grid| grid.height());
+
+        self.write_status(height)?;
+        while !self.controls.quit {
+            self.read_key(None)?;
+        }
+
+        Ok(())
+    }
+
+    /// Waits up to the timeout, or for as long as it takes when there isn't one, for a key and acts on it. Returns
+    /// whether a key was pressed.
+    fn read_key(&mut self, timeout: Option<Duration>) -> Result<bool, String> {
+        let error = |err: io::Error| format!("Unable to read the keyboard: {}", err);
+
+        if let Some(timeout) = timeout {
+            if !event::poll(timeout).map_err(error)? {
+                return Ok(false)
+            }
+        }
+
+        match event::read().map_err(error)? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                self.controls.apply(Action::from_key(key));
+                Ok(true)
+            },
+            _ => Ok(false),
+        }
+    }
+
+    fn write_status(&mut self, height: usize) -> Result<(), String> {
+        let state = match (self.finished, self.controls.paused) {
+            (true, _) => "finished",
+            (false, true) => "paused",
+            (false, false) => "running",
+        };
+
+        let status = format!("\x1b[{};1H{}frame {} | {} fps | {}{} | space pause, n step, +/- speed, q quit{}",
+                             height + 2, HIGHLIGHT, self.frame, self.controls.fps, state, RESET, CLEAR_TO_END_OF_LINE);
+        self.write(&status)
+    }
+
+    fn write(&mut self, text: &str) -> Result<(), String> {
+        self.stdout.write_all(text.as_bytes())
+            .and_then(|_| self.stdout.flush())
+            .map_err(|err| format!("Unable to write to the terminal: {}", err))
+    }
+}
+
+impl Drop for Viewer {
+    fn drop(&mut self) {
+        let _ = self.write(LEAVE_ALTERNATE_SCREEN);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{draw, Action, Controls, HIGHLIGHT, MAX_FPS, RESET};
+    use crate::grid::Grid;
+    use crate::parse::parse;
+
+    #[test]
+    fn test_actions() {
+        assert_eq!(Action::from_key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE)), Action::TogglePause);
+        assert_eq!(Action::from_key(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE)), Action::Step);
+        assert_eq!(Action::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), Action::Quit);
+        assert_eq!(Action::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE)), Action::Nothing);
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut controls = Controls::new(30);
+
+        controls.apply(Action::Step);
+        assert!(controls.paused && !controls.step);
+        controls.apply(Action::Step);
+        assert!(controls.step);
+        controls.apply(Action::TogglePause);
+        assert!(!controls.paused);
+
+        controls.apply(Action::Slower);
+        assert_eq!(controls.fps, 15);
+        for _ in 0..10 {
+            controls.apply(Action::Faster);
+        }
+        assert_eq!(controls.fps, MAX_FPS);
+        assert_eq!(Controls::new(0).fps, 1);
+    }
+
+    #[test]
+    fn test_draw() {
+        let before: Grid<char> = parse("L.L\nLLL\n");
+        let after: Grid<char> = parse("#.L\nL##\n");
+
+        assert_eq!(draw(&before, None), "L.L\x1b[K\r\nLLL\x1b[K\r\n");
+        assert_eq!(draw(&after, Some(&before)),
+                   format!("{h}#{r}.L\x1b[K\r\nL{h}##{r}\x1b[K\r\n", h = HIGHLIGHT, r = RESET));
+        assert_eq!(draw(&after, Some(&Grid::new(1, 1, '.'))), "#.L\x1b[K\r\nL##\x1b[K\r\n");
+    }
+}
@@ -0,0 +1,275 @@
+//! Finding words and 2D patterns in grids of letters.
+
+use crate::grid::{Direction, Grid, Point};
+use crate::wrap::{Wrap, WrappingGrid};
+
+/// Where a word starts and which way it reads
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WordMatch {
+    pub start: Point,
+    pub direction: Direction,
+}
+
+/// How a pattern was turned to make a match: mirrored left to right first if `reflected`, then turned clockwise a
+/// quarter turn at a time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Orientation {
+    pub quarter_turns: u8,
+    pub reflected: bool,
+}
+
+/// Where the top left corner of a turned pattern sits, and how it was turned
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PatternMatch {
+    pub position: Point,
+    pub orientation: Orientation,
+}
+
+/// A 2D template to look for, where cells set to `None` match any letter
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+    template: Grid<Option<char>>,
+    /// Every orientation the template can be tried in, sorted
+    orientations: Vec<Orientation>,
+    /// Each distinct way the template can be turned, with the first orientation that gives it
+    variants: Vec<(Orientation, Grid<Option<char>>)>,
+}
+
+impl Pattern {
+    /// Builds a pattern from rows of text, where the wildcard character matches any letter. It's only tried as
+    /// written until rotations or reflections are allowed.
+    pub fn new(template: &str, wildcard: char) -> Pattern {
+        let rows = template.lines()
+            .map(|line| line.chars().map(|c| if c == wildcard { None } else { Some(c) }).collect())
+            .collect();
+
+        let template: Grid<Option<char>> = Grid::from_rows(rows);
+
+        Pattern {
+            variants: vec![(Orientation::default(), template.clone())],
+            template,
+            orientations: vec![Orientation::default()],
+        }
+    }
+
+    /// Also tries the pattern turned by each quarter turn
+    pub fn with_rotations(self) -> Pattern {
+        self.with_orientations(|orientation| {
+            (0..4).map(|quarter_turns| Orientation { quarter_turns, ..orientation }).collect()
+        })
+    }
+
+    /// Also tries the pattern mirrored left to right
+    pub fn with_reflections(self) -> Pattern {
+        self.with_orientations(|orientation| {
+            vec![orientation, Orientation { reflected: true, ..orientation }]
+        })
+    }
+
+    /// Replaces each orientation with the ones the function makes from it, then works out the variants again from the
+    /// template. Going through the orientations in order means the same options give the same variants, whichever
+    /// order they were asked for in. Variants that look the same as an earlier one are dropped, so a symmetrical
+    /// pattern isn't found more than once in the same place.
+    fn with_orientations(self, expand: impl Fn(Orientation) -> Vec<Orientation>) -> Pattern {
+        let mut orientations: Vec<Orientation> = self.orientations.iter()
+            .flat_map(|orientation| expand(*orientation))
+            .collect();
+        orientations.sort();
+        orientations.dedup();
+
+        let mut variants: Vec<(Orientation, Grid<Option<char>>)> = vec![];
+        for orientation in &orientations {
+            let cells = oriented(&self.template, *orientation);
+            if !variants.iter().any(|(_, existing)| *existing == cells) {
+                variants.push((*orientation, cells));
+            }
+        }
+
+        Pattern { template: self.template, orientations, variants }
+    }
+}
+
+/// The template mirrored left to right if the orientation says so, then turned clockwise
+fn oriented(template: &Grid<Option<char>>, orientation: Orientation) -> Grid<Option<char>> {
+    let mut cells = if orientation.reflected {
+        Grid::from_fn(template.width(), template.height(), |point| {
+            template[Point::new(template.width() as i64 - 1 - point.x, point.y)]
+        })
+    } else {
+        template.clone()
+    };
+
+    for _ in 0..orientation.quarter_turns {
+        cells = cells.rotate_right();
+    }
+
+    cells
+}
+
+/// A search of one grid, in the chosen directions and with or without wrapping around the edges
+pub struct WordSearch<'a> {
+    grid: &'a Grid<char>,
+    directions: Vec<Direction>,
+    wrap: bool,
+}
+
+impl<'a> WordSearch<'a> {
+    /// Searches in all eight directions, without wrapping
+    pub fn new(grid: &'a Grid<char>) -> WordSearch<'a> {
+        WordSearch { grid, directions: Direction::ALL.to_vec(), wrap: false }
+    }
+
+    /// Only reads words in these directions
+    pub fn with_directions(mut self, directions: &[Direction]) -> WordSearch<'a> {
+        self.directions = directions.to_vec();
+        self
+    }
+
+    /// Carries words and patterns that run off an edge on from the opposite edge
+    pub fn with_wrapping(mut self) -> WordSearch<'a> {
+        self.wrap = true;
+        self
+    }
+
+    /// Every place the word can be read, in reading order of the first letter and then by direction. A word that reads
+    /// the same both ways is found once in each direction.
+    pub fn find(&self, word: &str) -> Vec<WordMatch> {
+        let letters: Vec<char> = word.chars().collect();
+        let Some(first) = letters.first() else {
+            return vec![]
+        };
+
+        let mut matches = vec![];
+
+        for start in self.grid.find_all(first) {
+            for &direction in &self.directions {
+                let reads = letters.iter()
+                    .enumerate()
+                    .all(|(index, letter)| self.get(start + direction.offset() * index as i64) == Some(letter));
+
+                if reads {
+                    matches.push(WordMatch { start, direction });
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// Every place the pattern fits in any of its orientations, in reading order of the top left corner
+    pub fn find_pattern(&self, pattern: &Pattern) -> Vec<PatternMatch> {
+        let mut matches = vec![];
+
+        for position in self.grid.points() {
+            for (orientation, cells) in &pattern.variants {
+                // Wildcards still have to land on the grid, so the whole pattern fits
+                let fits = cells.iter().all(|(offset, cell)| match self.get(position + offset) {
+                    Some(letter) => cell.is_none() || *cell == Some(*letter),
+                    None => false,
+                });
+
+                if fits {
+                    matches.push(PatternMatch { position, orientation: *orientation });
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// The letter at the point, wrapping it back onto the grid if the search wraps
+    fn get(&self, point: Point) -> Option<&char> {
+        match self.wrap {
+            true => WrappingGrid::new(self.grid, Wrap::Both).get(point),
+            false => self.grid.get(point),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Orientation, Pattern, PatternMatch, WordMatch, WordSearch};
+    use crate::grid::{Direction, Grid, Point};
+    use crate::parse::parse;
+
+    #[test]
+    fn test_find() {
+        let grid: Grid<char> = parse("CATS\nAAXA\nTXTC\n");
+
+        assert_eq!(WordSearch::new(&grid).find("CAT"), vec![
+            WordMatch { start: Point::new(0, 0), direction: Direction::East },
+            WordMatch { start: Point::new(0, 0), direction: Direction::SouthEast },
+            WordMatch { start: Point::new(0, 0), direction: Direction::South },
+        ]);
+        assert_eq!(WordSearch::new(&grid).with_directions(&Direction::CARDINAL).find("CAT").len(), 2);
+        assert!(WordSearch::new(&grid).find("").is_empty());
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid: Grid<char> = parse("CATS\nAAXA\nTXTC\n");
+
+        assert!(WordSearch::new(&grid).find("SC").is_empty());
+        assert_eq!(WordSearch::new(&grid).with_wrapping().find("SC"), vec![
+            WordMatch { start: Point::new(3, 0), direction: Direction::North },
+            WordMatch { start: Point::new(3, 0), direction: Direction::East },
+        ]);
+        assert_eq!(WordSearch::new(&grid).with_wrapping().with_directions(&[Direction::North]).find("CT").len(), 1);
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid: Grid<char> = parse("M.S\n.A.\nM.S\n");
+        let cross = Pattern::new("M.S\n.A.\nM.S", '.');
+
+        assert_eq!(WordSearch::new(&grid).find_pattern(&cross), vec![
+            PatternMatch { position: Point::new(0, 0), orientation: Orientation::default() },
+        ]);
+
+        let turned: Grid<char> = parse("S.S\n.A.\nM.M\n");
+        assert!(WordSearch::new(&turned).find_pattern(&cross).is_empty());
+        assert_eq!(WordSearch::new(&turned).find_pattern(&cross.clone().with_rotations()), vec![
+            PatternMatch { position: Point::new(0, 0), orientation: Orientation { quarter_turns: 3, reflected: false } },
+        ]);
+
+        // Mirroring the cross gives the same shapes as turning it, so each one is only found once
+        assert_eq!(cross.with_rotations().with_reflections().variants.len(), 4);
+    }
+
+    #[test]
+    fn test_pattern_edges() {
+        let grid: Grid<char> = parse("AB\nCD\n");
+        let pattern = Pattern::new("B?\n??", '?');
+
+        assert!(WordSearch::new(&grid).find_pattern(&pattern).is_empty());
+        assert_eq!(WordSearch::new(&grid).with_wrapping().find_pattern(&pattern), vec![
+            PatternMatch { position: Point::new(1, 0), orientation: Orientation::default() },
+        ]);
+    }
+
+    #[test]
+    fn test_pattern_reflections() {
+        let grid: Grid<char> = parse("AB\nC.\n");
+        let pattern = Pattern::new("BA\n?C", '?').with_reflections();
+
+        assert_eq!(WordSearch::new(&grid).find_pattern(&pattern), vec![
+            PatternMatch { position: Point::new(0, 0), orientation: Orientation { quarter_turns: 0, reflected: true } },
+        ]);
+    }
+
+    #[test]
+    fn test_pattern_builder_order() {
+        // Mirrored, "AB/C?" is "BA/?C", and turning that once clockwise gives "?B/CA"
+        let grid: Grid<char> = parse("XB\nCA\n");
+        let rotated_first = Pattern::new("AB\nC?", '?').with_rotations().with_reflections();
+        let reflected_first = Pattern::new("AB\nC?", '?').with_reflections().with_rotations();
+
+        assert_eq!(rotated_first, reflected_first);
+        assert_eq!(rotated_first.variants.len(), 8);
+        let turned_mirror = Orientation { quarter_turns: 1, reflected: true };
+        for pattern in [&rotated_first, &reflected_first] {
+            assert_eq!(WordSearch::new(&grid).find_pattern(pattern),
+                       vec![PatternMatch { position: Point::new(0, 0), orientation: turned_mirror }]);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Grids whose edges join up, like a map that repeats to the right forever.
+
+use std::iter;
+use std::ops::Index;
+
+use crate::grid::{Grid, Point};
+
+/// Which of a grid's edges carry on from the opposite edge
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wrap {
+    /// Left and right join up, so each row repeats forever
+    Horizontal,
+    /// Top and bottom join up, so each column repeats forever
+    Vertical,
+    Both,
+}
+
+/// A view of a grid that wraps around some of its edges
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+    wrap: Wrap,
+}
+
+impl<'a, T> WrappingGrid<'a, T> {
+    pub fn new(grid: &'a Grid<T>, wrap: Wrap) -> WrappingGrid<'a, T> {
+        WrappingGrid { grid, wrap }
+    }
+
+    /// The grid being wrapped
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// The point on the grid that the given point wraps onto, or `None` if it's past an edge that doesn't wrap
+    pub fn wrap_point(&self, point: Point) -> Option<Point> {
+        let (width, height) = (self.grid.width() as i64, self.grid.height() as i64);
+        if width == 0 || height == 0 {
+            return None
+        }
+
+        let wrapped = match self.wrap {
+            Wrap::Horizontal => Point::new(point.x.rem_euclid(width), point.y),
+            Wrap::Vertical => Point::new(point.x, point.y.rem_euclid(height)),
+            Wrap::Both => Point::new(point.x.rem_euclid(width), point.y.rem_euclid(height)),
+        };
+
+        Some(wrapped).filter(|wrapped| self.grid.in_bounds(*wrapped))
+    }
+
+    /// The cell the point wraps onto, or `None` if it's past an edge that doesn't wrap
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        self.wrap_point(point).map(|point| &self.grid[point])
+    }
+
+    /// The cells reached by taking the same step over and over from the starting point, not including the start,
+    /// until one is past an edge that doesn't wrap. Each cell comes with the point on the grid it wrapped onto. When
+    /// the step never leaves the grid, such as on a grid that wraps both ways, this goes on forever.
+    pub fn slope(&self, from: Point, step: Point) -> impl Iterator<Item = (Point, &'a T)> {
+        let (grid, view) = (self.grid, WrappingGrid { grid: self.grid, wrap: self.wrap });
+
+        iter::successors(Some(from + step), move |point| Some(*point + step))
+            .map_while(move |point| view.wrap_point(point))
+            .map(move |point| (point, &grid[point]))
+    }
+
+    /// How many cells on the slope from the starting point hold the value
+    pub fn count_on_slope(&self, from: Point, step: Point, value: &T) -> usize where T: PartialEq {
+        self.slope(from, step).filter(|(_, cell)| *cell == value).count()
+    }
+}
+
+impl<T> Index<Point> for WrappingGrid<'_, T> {
+    type Output = T;
+
+    /// The cell the point wraps onto. Panics if the point is past an edge that doesn't wrap.
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{} is off the edge of the grid", point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Wrap, WrappingGrid};
+    use crate::grid::{Grid, Point};
+    use crate::parse::parse;
+
+    #[test]
+    fn test_get() {
+        let grid: Grid<char> = parse("ab\ncd\nef\n");
+        let horizontal = WrappingGrid::new(&grid, Wrap::Horizontal);
+        let vertical = WrappingGrid::new(&grid, Wrap::Vertical);
+        let both = WrappingGrid::new(&grid, Wrap::Both);
+
+        assert_eq!(horizontal.get(Point::new(5, 1)), Some(&'d'));
+        assert_eq!(horizontal.get(Point::new(-1, 0)), Some(&'b'));
+        assert_eq!(horizontal.get(Point::new(0, 3)), None);
+        assert_eq!(vertical.get(Point::new(0, -1)), Some(&'e'));
+        assert_eq!(vertical.get(Point::new(2, 0)), None);
+        assert_eq!(both[Point::new(-3, 7)], 'd');
+        assert_eq!(WrappingGrid::new(&Grid::<char>::from_rows(vec![]), Wrap::Both).get(Point::ORIGIN), None);
+    }
+
+    #[test]
+    fn test_slope() {
+        let grid: Grid<char> = parse("..#\n#..\n.#.\n..#\n");
+        let view = WrappingGrid::new(&grid, Wrap::Horizontal);
+
+        let cells: Vec<(Point, char)> = view.slope(Point::ORIGIN, Point::new(2, 1))
+            .map(|(point, cell)| (point, *cell))
+            .collect();
+        assert_eq!(cells, vec![(Point::new(2, 1), '.'), (Point::new(1, 2), '#'), (Point::new(0, 3), '.')]);
+        assert_eq!(view.count_on_slope(Point::ORIGIN, Point::new(1, 1), &'#'), 0);
+        assert_eq!(view.count_on_slope(Point::ORIGIN, Point::new(4, 2), &'#'), 1);
+        assert_eq!(WrappingGrid::new(&grid, Wrap::Both).slope(Point::ORIGIN, Point::new(1, 1)).take(10).count(), 10);
+    }
+}
@@ -0,0 +1,71 @@
+//! Compiling a year's inputs and examples into the binary, for the `embed` feature. Each year crate's `build.rs`
+//! calls `build_script`, and the year hands the table it writes to `aoc_common::input::embed`.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The name of the generated table within `OUT_DIR`
+pub const EMBEDDED_FILE: &str = "embedded_inputs.rs";
+
+/// The directory within a year crate holding its inputs and examples, as in `aoc_common::input::crate_input_dir`
+const INPUT_DIR: &str = "input";
+
+/// Every file under the directory, sorted, leaving out hidden files
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+            .flat_map(|entry| {
+                let path = entry.path();
+                if path.is_dir() { files(&path) } else { vec![path] }
+            })
+            .collect())
+        .unwrap_or_default();
+    files.sort();
+
+    files
+}
+
+/// The source of a table holding each file's path and contents
+pub fn embedded_table(files: &[PathBuf]) -> String {
+    let mut table = String::from("&[\n");
+    for path in files {
+        let path = path.display().to_string();
+        let _ = writeln!(table, "    ({:?}, include_bytes!({:?}) as &[u8]),", path, path);
+    }
+    table.push(']');
+
+    table
+}
+
+/// Writes the table of the crate's inputs for `include!`, when the `embed` feature is on. Meant to be called from
+/// a year crate's `build.rs`.
+pub fn build_script() {
+    println!("cargo:rerun-if-changed={}", INPUT_DIR);
+
+    if std::env::var_os("CARGO_FEATURE_EMBED").is_none() {
+        return
+    }
+
+    let crate_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set"));
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").expect("OUT_DIR is not set"));
+
+    let table = embedded_table(&files(&crate_dir.join(INPUT_DIR)));
+    fs::write(out_dir.join(EMBEDDED_FILE), table).expect("Unable to write the embedded inputs");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::embedded_table;
+
+    #[test]
+    fn test_embedded_table() {
+        let table = embedded_table(&[PathBuf::from("/repo/aoc-2024/input/day1_input.txt")]);
+        assert_eq!(table, "&[\n    (\"/repo/aoc-2024/input/day1_input.txt\", \
+                           include_bytes!(\"/repo/aoc-2024/input/day1_input.txt\") as &[u8]),\n]");
+    }
+}
@@ -0,0 +1,172 @@
+//! The manifest of known answers, with each answer kept against an id derived from its input's contents.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Identifies a single part of a single day, solved with a particular input
+type AnswerKey = (u16, u8, u8, String);
+
+/// How a part's answer compares with the manifest
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Correct,
+    Wrong { expected: String },
+    /// No answer has been recorded for this input yet
+    Unknown,
+    /// The solution panicked or the input couldn't be read, so there is no answer to check
+    Error,
+}
+
+impl Status {
+    pub fn check(answer: &Result<String, String>, expected: Option<&str>) -> Status {
+        match (answer, expected) {
+            (Err(_), _) => Status::Error,
+            (Ok(_), None) => Status::Unknown,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Correct,
+            (Ok(_), Some(expected)) => Status::Wrong { expected: expected.to_string() },
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Correct => "pass",
+            Status::Wrong { .. } => "FAIL",
+            Status::Unknown => "new",
+            Status::Error => "ERROR",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Wrong { .. } | Status::Error)
+    }
+}
+
+/// The manifest at the root of the workspace as it was when the binary was built, with the `embed` feature
+#[cfg(feature = "embed")]
+const EMBEDDED_MANIFEST: Option<&str> = Some(include_str!("../../answers.tsv"));
+#[cfg(not(feature = "embed"))]
+const EMBEDDED_MANIFEST: Option<&str> = None;
+
+/// The manifest kept at the root of the workspace
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("answers.tsv")
+}
+
+/// A short id for an input, based on a 64-bit FNV-1a hash of its contents. Line endings and trailing whitespace
+/// are ignored so the same input saved on a different machine keeps the same id.
+pub fn input_id(input: &str) -> String {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let normalised: Vec<&str> = input.trim_end().lines().map(str::trim_end).collect();
+    let hash = normalised.join("\n").bytes()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME));
+
+    format!("{:016x}", hash)
+}
+
+/// The known answers, as loaded from (and saved back to) the manifest file
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<AnswerKey, String>,
+}
+
+impl Answers {
+    /// Loads the manifest at the given path. A missing file is treated as an empty manifest so that recording can
+    /// create it, unless it's the default manifest and a copy was embedded in the binary.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound && path == default_path() => {
+                EMBEDDED_MANIFEST.unwrap_or_default().to_string()
+            },
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("Unable to read {}: {}", path.display(), err)),
+        };
+
+        let entries = parse_manifest(&contents).map_err(|message| format!("{}: {}", path.display(), message))?;
+
+        Ok(Answers { path: path.to_path_buf(), entries })
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: u8, input_id: &str) -> Option<&str> {
+        self.entries.get(&(year, day, part, input_id.to_string())).map(String::as_str)
+    }
+
+    /// Adds the answer if there isn't one for this input already. Existing answers are never replaced, since a
+    /// mismatch means the solution is wrong rather than the manifest. Returns whether the answer was added.
+    pub fn record(&mut self, year: u16, day: u8, part: u8, input_id: &str, answer: &str) -> bool {
+        let key = (year, day, part, input_id.to_string());
+        if self.entries.contains_key(&key) {
+            return false
+        }
+
+        self.entries.insert(key, answer.to_string());
+        true
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut contents = String::from("# year\tday\tpart\tinput\tanswer\n");
+        for ((year, day, part, input_id), answer) in &self.entries {
+            contents.push_str(&format!("{}\t{}\t{}\t{}\t{}\n", year, day, part, input_id, answer));
+        }
+
+        fs::write(&self.path, contents).map_err(|err| format!("Unable to write {}: {}", self.path.display(), err))
+    }
+}
+
+fn parse_manifest(contents: &str) -> Result<BTreeMap<AnswerKey, String>, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let invalid = |name: &str| format!("line {}: invalid {}", index + 1, name);
+
+            match line.split('\t').collect::<Vec<&str>>()[..] {
+                [year, day, part, input_id, answer] => Ok((
+                    (
+                        year.parse().map_err(|_| invalid("year"))?,
+                        day.parse().map_err(|_| invalid("day"))?,
+                        part.parse().map_err(|_| invalid("part"))?,
+                        input_id.to_string(),
+                    ),
+                    answer.to_string(),
+                )),
+                _ => Err(format!("line {}: expected 5 fields", index + 1)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_id, parse_manifest, Status};
+
+    #[test]
+    fn test_input_id() {
+        assert_eq!(input_id("1\n2\n3\n"), input_id("1\r\n2\r\n3"));
+        assert_eq!(input_id("1 \n2\n3\n\n"), input_id("1\n2\n3"));
+        assert_ne!(input_id("1\n2\n3\n"), input_id("1\n2\n4\n"));
+        assert_eq!(input_id(""), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let entries = parse_manifest("# header\n2024\t6\t1\tabc\t4988\n\n2024\t6\t2\tabc\t1697\n").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[&(2024, 6, 2, "abc".to_string())], "1697");
+
+        assert!(parse_manifest("2024\t6\t1\tabc\n").is_err());
+        assert!(parse_manifest("2024\tsix\t1\tabc\t4988\n").is_err());
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(Status::check(&Ok("42".to_string()), Some("42")), Status::Correct);
+        assert_eq!(Status::check(&Ok("41".to_string()), Some("42")), Status::Wrong { expected: "42".to_string() });
+        assert_eq!(Status::check(&Ok("42".to_string()), None), Status::Unknown);
+        assert_eq!(Status::check(&Err("boom".to_string()), Some("42")), Status::Error);
+    }
+}
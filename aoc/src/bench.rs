@@ -0,0 +1,160 @@
+//! Repeated timing of solutions, with baselines saved to disk so optimisations can be checked for real gains.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Timing statistics for one part, gathered over many runs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|sample| sample.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Identifies a single part of a single day
+pub type BenchKey = (u16, u8, u8);
+
+/// Saved results to compare future runs against, keyed by year, day and part
+pub type Baseline = HashMap<BenchKey, Stats>;
+
+/// Writes the results as tab separated lines: year, day, part, then min, median, mean and stddev in nanoseconds
+pub fn save_baseline(path: &Path, results: &[(BenchKey, Stats)]) -> Result<(), String> {
+    let mut contents = String::from("# year\tday\tpart\tmin_ns\tmedian_ns\tmean_ns\tstddev_ns\n");
+
+    for ((year, day, part), stats) in results {
+        contents.push_str(&format!("{}\t{}\t{}\t{}\t{}\t{}\t{}\n", year, day, part, stats.min.as_nanos(),
+                                   stats.median.as_nanos(), stats.mean.as_nanos(), stats.stddev.as_nanos()));
+    }
+
+    fs::write(path, contents).map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+
+    parse_baseline(&contents).map_err(|message| format!("{}: {}", path.display(), message))
+}
+
+fn parse_baseline(contents: &str) -> Result<Baseline, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let fields: Vec<u64> = line.split('\t')
+                .map(|field| field.trim().parse::<u64>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| format!("line {}: invalid number", index + 1))?;
+
+            let out_of_range = |name: &str, value: u64| {
+                format!("line {}: {} {} is out of range", index + 1, name, value)
+            };
+
+            match fields[..] {
+                [year, day, part, min, median, mean, stddev] => Ok((
+                    (u16::try_from(year).map_err(|_| out_of_range("year", year))?,
+                     u8::try_from(day).map_err(|_| out_of_range("day", day))?,
+                     u8::try_from(part).map_err(|_| out_of_range("part", part))?),
+                    Stats {
+                        min: Duration::from_nanos(min),
+                        median: Duration::from_nanos(median),
+                        mean: Duration::from_nanos(mean),
+                        stddev: Duration::from_nanos(stddev),
+                    },
+                )),
+                _ => Err(format!("line {}: expected 7 fields, found {}", index + 1, fields.len())),
+            }
+        })
+        .collect()
+}
+
+/// How much slower (positive) or faster (negative) the median is than the baseline's, as a percentage. A baseline
+/// median of zero can't be compared against, so that gives `None`.
+pub fn percent_change(baseline: &Stats, current: &Stats) -> Option<f64> {
+    if baseline.median.is_zero() {
+        return None
+    }
+
+    let baseline = baseline.median.as_nanos() as f64;
+    let current = current.median.as_nanos() as f64;
+
+    Some((current - baseline) / baseline * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_baseline, percent_change, Stats};
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|value| Duration::from_millis(*value)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[4, 2, 9, 5, 4, 5, 7, 4]));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.stddev, Duration::from_millis(2));
+
+        assert_eq!(Stats::from_samples(&ms(&[3, 1, 2])).median, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_parse_baseline() {
+        let baseline = parse_baseline("# header\n2024\t6\t2\t100\t200\t300\t40\n\n").unwrap();
+        let stats = baseline[&(2024, 6, 2)];
+        assert_eq!(stats.median, Duration::from_nanos(200));
+        assert_eq!(stats.stddev, Duration::from_nanos(40));
+
+        assert!(parse_baseline("2024\t6\t2\t100\n").is_err());
+        assert!(parse_baseline("2024\tsix\t2\t100\t200\t300\t40\n").is_err());
+        assert_eq!(parse_baseline("2024\t262\t2\t100\t200\t300\t40\n"),
+                   Err("line 1: day 262 is out of range".to_string()));
+        assert!(parse_baseline("67560\t6\t2\t100\t200\t300\t40\n").is_err());
+    }
+
+    #[test]
+    fn test_percent_change() {
+        let baseline = Stats::from_samples(&ms(&[10]));
+        assert_eq!(percent_change(&baseline, &Stats::from_samples(&ms(&[12]))), Some(20.0));
+        assert_eq!(percent_change(&baseline, &Stats::from_samples(&ms(&[5]))), Some(-50.0));
+
+        let instant = Stats::from_samples(&ms(&[0]));
+        assert_eq!(percent_change(&instant, &Stats::from_samples(&ms(&[5]))), None);
+        assert_eq!(percent_change(&instant, &instant), None);
+    }
+}
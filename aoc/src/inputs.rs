@@ -0,0 +1,145 @@
+//! Encrypting and decrypting the inputs kept in the repository, for `aoc inputs`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::crypt::{self, Key, ENCRYPTED_EXTENSION};
+use aoc_common::input::users;
+
+/// The plain input files in a directory: a year's `dayN_input.txt` files, or a user's `N.txt` files
+fn plain_inputs(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter(|path| path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".txt"))
+                .is_some_and(|stem| stem.ends_with("_input") || stem.parse::<u8>().is_ok()))
+            .collect())
+        .unwrap_or_default();
+    paths.sort();
+
+    paths
+}
+
+/// The encrypted input files in a directory, along with where each one's plain copy goes
+fn encrypted_inputs(dir: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let suffix = format!(".{}", ENCRYPTED_EXTENSION);
+    let mut paths: Vec<(PathBuf, PathBuf)> = fs::read_dir(dir)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter_map(|path| {
+                let plain = path.to_str()?.strip_suffix(&suffix).map(PathBuf::from)?;
+                Some((path, plain))
+            })
+            .collect())
+        .unwrap_or_default();
+    paths.sort();
+
+    paths
+}
+
+/// Every directory that holds inputs for the given years: each year's input directory, then each user's
+pub fn input_dirs(years: &[(u16, PathBuf)], users_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = years.iter().map(|(_, dir)| dir.clone()).collect();
+
+    for user in users(users_dir) {
+        dirs.extend(years.iter().map(|(year, _)| users_dir.join(&user).join(year.to_string())));
+    }
+
+    dirs
+}
+
+/// Writes an encrypted copy of every plain input, removing the plain files unless asked to keep them
+pub fn encrypt(dirs: &[PathBuf], key: &Key, keep: bool) -> Result<(), String> {
+    let mut encrypted = 0;
+
+    for path in dirs.iter().flat_map(|dir| plain_inputs(dir)) {
+        let input = fs::read_to_string(&path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+        let encrypted_path = crypt::encrypted_path(&path);
+
+        let unchanged = fs::read(&encrypted_path).ok()
+            .and_then(|data| crypt::decrypt(key, &data).ok())
+            .is_some_and(|existing| existing == input);
+
+        if !unchanged {
+            let data = crypt::encrypt(key, &input)?;
+            fs::write(&encrypted_path, data)
+                .map_err(|err| format!("Unable to write {}: {}", encrypted_path.display(), err))?;
+            println!("Encrypted {}", path.display());
+            encrypted += 1;
+        }
+
+        if !keep {
+            fs::remove_file(&path).map_err(|err| format!("Unable to remove {}: {}", path.display(), err))?;
+        }
+    }
+
+    println!("Encrypted {} {}", encrypted, if encrypted == 1 { "input" } else { "inputs" });
+
+    Ok(())
+}
+
+/// Writes a plain copy of every encrypted input, leaving any plain file that's already there alone
+pub fn decrypt(dirs: &[PathBuf], key: &Key) -> Result<(), String> {
+    let mut decrypted = 0;
+
+    for (path, plain) in dirs.iter().flat_map(|dir| encrypted_inputs(dir)) {
+        if plain.exists() {
+            println!("Skipped {}, which is already there", plain.display());
+            continue
+        }
+
+        let input = crypt::read_encrypted(&path, key)?;
+        fs::write(&plain, input).map_err(|err| format!("Unable to write {}: {}", plain.display(), err))?;
+        println!("Decrypted {}", plain.display());
+        decrypted += 1;
+    }
+
+    println!("Decrypted {} {}", decrypted, if decrypted == 1 { "input" } else { "inputs" });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_common::crypt::Key;
+
+    use super::{decrypt, encrypt, input_dirs};
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-test-{}", std::process::id()));
+        let year_dir = root.join("aoc-2024").join("input");
+        let user_dir = root.join("inputs").join("sam").join("2024");
+        fs::create_dir_all(year_dir.join("examples")).unwrap();
+        fs::create_dir_all(&user_dir).unwrap();
+        fs::write(year_dir.join("day1_input.txt"), "3   4\n").unwrap();
+        fs::write(year_dir.join("examples").join("day1_example1.txt"), "part 1: 11\n---\n3   4\n").unwrap();
+        fs::write(user_dir.join("1.txt"), "4   3\n").unwrap();
+
+        let key = Key::generate().unwrap();
+        let dirs = input_dirs(&[(2024, year_dir.clone())], &root.join("inputs"));
+
+        encrypt(&dirs, &key, false).unwrap();
+        assert!(!year_dir.join("day1_input.txt").exists());
+        assert!(year_dir.join("day1_input.txt.enc").exists());
+        assert!(user_dir.join("1.txt.enc").exists());
+        assert!(year_dir.join("examples").join("day1_example1.txt").exists());
+
+        decrypt(&dirs, &key).unwrap();
+        assert_eq!(fs::read_to_string(year_dir.join("day1_input.txt")).unwrap(), "3   4\n");
+        assert_eq!(fs::read_to_string(user_dir.join("1.txt")).unwrap(), "4   3\n");
+
+        let encrypted = fs::read(year_dir.join("day1_input.txt.enc")).unwrap();
+        encrypt(&dirs, &key, true).unwrap();
+        assert_eq!(fs::read(year_dir.join("day1_input.txt.enc")).unwrap(), encrypted);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,996 @@
+use std::env;
+use std::io::{self, IsTerminal, Read};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, SystemTime};
+
+use aoc_common::crypt::{self, Key};
+use aoc_common::grid::Grid;
+use aoc_common::input::{input_exists, is_valid_user, read_input, root_input_dir, user_input_path, users};
+use aoc_common::render::{self, GifWriter, Image, PngSequence};
+use aoc_common::view::Viewer;
+use aoc_common::{Frames, PartRun, Puzzle, PuzzleRun, Year};
+
+use answers::{Answers, Status};
+use bench::{BenchKey, Stats};
+use report::{DayResult, Format};
+
+mod answers;
+mod bench;
+mod inputs;
+mod report;
+mod site;
+mod submit;
+
+const USAGE: &str = "\
+Usage:
+    aoc [--input-root PATH] <command>
+
+Commands:
+    aoc list
+    aoc run <year> <day> [--part N] [--input PATH | --text INPUT]
+                         [--user NAME | --all-users]
+                         [--record] [--answers PATH]
+    aoc run [<year>] --all [--format table|json|csv] [--user NAME | --all-users]
+                           [--record] [--answers PATH]
+    aoc bench [<year> [<day>]] [--part N] [--runs N] [--warmup N]
+              [--save PATH] [--compare PATH] [--threshold PERCENT]
+    aoc fetch <year> <day>
+    aoc submit <year> <day> <part> [<answer>] [--answers PATH] [--ledger PATH]
+    aoc render <year> <day> [--part N] [--output PATH] [--scale N] [--every N]
+                            [--delay MS]
+    aoc watch <year> <day> [--part N] [--fps N]
+    aoc inputs key
+    aoc inputs encrypt [<year>] [--keep]
+    aoc inputs decrypt [<year>]
+
+Inputs are read from each year's input/ directory, or from <root>/<year>/ when
+--input-root or the AOC_INPUT_ROOT environment variable is set. Other people's
+inputs live in inputs/<user>/<year>/<day>.txt: --user runs with one person's
+inputs, and --all-users runs with everyone's inputs as well as the default ones.
+--input - reads the input from stdin, and --text gives the input itself.
+
+Inputs can be kept encrypted as <input>.enc, which is decrypted when the plain
+file is missing. The key is 64 hex digits, given in AOC_INPUT_KEY, in the file
+named by AOC_INPUT_KEY_FILE, or else in .aoc-input-key at the top of the
+workspace; `aoc inputs key` generates a new one.
+
+fetch downloads a day's input into the year's input directory once the puzzle
+has unlocked, and never downloads an input that's already there. It logs in
+with the session token in AOC_SESSION or ~/.config/aoc/session.
+
+submit sends an answer, solving the part first when no answer is given. Every
+attempt is kept in submissions.tsv, and answers the site has already turned
+down, or has ruled out as too high or too low, are refused without sending
+them. A correct answer is added to the answers manifest.
+
+render draws the days that simulate something on a grid. A .gif output gets an
+animation of every Nth step and the last one, a .png or .ppm output gets how it
+ends, and an output with no extension becomes a directory of numbered PNGs. The
+default is day<N>.gif, with 4 pixels per cell and 50ms per frame.
+
+watch plays the same simulations in the terminal, 30 frames a second unless
+--fps says otherwise, highlighting the cells that changed. Space pauses, n steps
+while paused, + and - change the speed, and q quits.";
+
+/// The name given to the inputs in each year's own input directory
+const DEFAULT_USER: &str = "default";
+
+/// Regressions smaller than this percentage are put down to noise
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Everything given on the command line
+struct Cli {
+    /// Read inputs from `<root>/<year>/` rather than each year's own input directory
+    input_root: Option<PathBuf>,
+    command: Command,
+}
+
+/// What the user asked for on the command line
+enum Command {
+    List,
+    Run(RunOptions),
+    Bench(BenchOptions),
+    Inputs(InputsOptions),
+    Fetch { year: u16, day: u8 },
+    Submit(SubmitOptions),
+    Render(RenderOptions),
+    Watch { year: u16, day: u8, part: u8, fps: u32 },
+}
+
+/// Which days to run, and how to check and report the answers
+struct RunOptions {
+    year: Option<u16>,
+    day: Option<u8>,
+    /// Run every day of the year, or of every year when no year is given, and print a summary
+    all: bool,
+    part: Option<u8>,
+    /// Read the input from this path, or from stdin when it's `-`
+    input: Option<PathBuf>,
+    /// Use this text as the input
+    text: Option<String>,
+    format: Format,
+    /// Use this person's inputs from the users directory
+    user: Option<String>,
+    /// Run with the default inputs and everyone's inputs from the users directory
+    all_users: bool,
+    users_dir: PathBuf,
+    /// Add answers that aren't in the manifest yet, once they've been verified
+    record: bool,
+    answers: PathBuf,
+}
+
+/// Which parts to benchmark, how hard, and what to do with the results
+struct BenchOptions {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    runs: usize,
+    warmup: usize,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    threshold: f64,
+}
+
+/// What `aoc inputs` should do
+enum InputsAction {
+    /// Print a new random key
+    Key,
+    Encrypt,
+    Decrypt,
+}
+
+/// Which inputs to encrypt or decrypt
+struct InputsOptions {
+    action: InputsAction,
+    year: Option<u16>,
+    /// Leave the plain inputs in place after encrypting them
+    keep: bool,
+}
+
+/// The answer to send and where to keep track of it
+struct SubmitOptions {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// Solve the part to get the answer when none is given
+    answer: Option<String>,
+    answers: PathBuf,
+    ledger: PathBuf,
+}
+
+/// Which simulation to draw, and how
+struct RenderOptions {
+    year: u16,
+    day: u8,
+    part: u8,
+    output: PathBuf,
+    /// How many pixels wide and high each cell is drawn
+    scale: usize,
+    /// Only draw every this many steps, along with the last one
+    every: usize,
+    /// How long each frame of a GIF is shown
+    delay: Duration,
+}
+
+/// Where the frames of `aoc render` end up
+enum FrameSink {
+    /// Created once the first frame gives the size
+    Gif(Option<GifWriter>),
+    Sequence(PngSequence),
+    /// Only the latest frame is kept, to be saved at the end
+    Last(Option<Image>),
+}
+
+impl FrameSink {
+    fn add(&mut self, image: Image, options: &RenderOptions) -> Result<(), String> {
+        match self {
+            FrameSink::Gif(writer) => {
+                if writer.is_none() {
+                    *writer = Some(GifWriter::create(&options.output, image.width(), image.height(), options.delay)?);
+                }
+
+                writer.as_mut().unwrap().add(&image)
+            },
+            FrameSink::Sequence(sequence) => sequence.add(&image),
+            FrameSink::Last(last) => {
+                *last = Some(image);
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Where other people's inputs live
+fn users_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("inputs")
+}
+
+/// Every year that has solutions, oldest first
+fn years() -> Vec<Year> {
+    vec![
+        aoc_2019::year(),
+        aoc_2020::year(),
+        aoc_2023::year(),
+        aoc_2024::year(),
+    ]
+}
+
+fn parse_number<T: std::str::FromStr>(value: Option<&String>, name: &str) -> Result<T, String> {
+    let value = value.ok_or(format!("Missing {}", name))?;
+
+    value.parse::<T>().map_err(|_| format!("Invalid {}: {}", name, value))
+}
+
+/// Splits off the leading arguments that aren't options, such as the year and day
+fn positional_args(args: &[String], max: usize) -> Result<Vec<&String>, String> {
+    let positional: Vec<&String> = args.iter().take_while(|arg| !arg.starts_with("--")).collect();
+    if positional.len() > max {
+        return Err(format!("Unexpected argument: {}", positional[max]))
+    }
+
+    Ok(positional)
+}
+
+fn parse_args(args: &[String]) -> Result<Cli, String> {
+    match args {
+        [option, root, rest @ ..] if option == "--input-root" => {
+            Ok(Cli { input_root: Some(PathBuf::from(root)), command: parse_command(rest)? })
+        },
+        [option] if option == "--input-root" => Err("Missing input root".to_string()),
+        _ => Ok(Cli { input_root: None, command: parse_command(args)? }),
+    }
+}
+
+fn parse_command(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("list") => Ok(Command::List),
+        Some("run") => {
+            let positional = positional_args(&args[1..], 2)?;
+
+            let mut run = RunOptions {
+                year: positional.first().map(|year| parse_number(Some(*year), "year")).transpose()?,
+                day: positional.get(1).map(|day| parse_number(Some(*day), "day")).transpose()?,
+                all: false,
+                part: None,
+                input: None,
+                text: None,
+                format: Format::Table,
+                user: None,
+                all_users: false,
+                users_dir: users_dir(),
+                record: false,
+                answers: answers::default_path(),
+            };
+
+            let mut options = args.iter().skip(1 + positional.len());
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--all" => run.all = true,
+                    "--part" => run.part = Some(parse_number(options.next(), "part")?),
+                    "--input" => run.input = Some(PathBuf::from(options.next().ok_or("Missing input path")?)),
+                    "--text" => run.text = Some(options.next().ok_or("Missing input text")?.clone()),
+                    "--format" => run.format = options.next().ok_or("Missing format")?.parse()?,
+                    "--user" => {
+                        let user = options.next().ok_or("Missing user")?;
+                        if !is_valid_user(user) {
+                            return Err(format!("Invalid user: {}", user))
+                        }
+                        run.user = Some(user.clone());
+                    },
+                    "--all-users" => run.all_users = true,
+                    "--record" => run.record = true,
+                    "--answers" => run.answers = PathBuf::from(options.next().ok_or("Missing answers path")?),
+                    _ => return Err(format!("Unknown option: {}", option)),
+                }
+            }
+
+            let sources = [run.input.is_some(), run.text.is_some(), run.user.is_some(), run.all_users];
+            if sources.iter().filter(|set| **set).count() > 1 {
+                return Err("Only one of --input, --text, --user and --all-users can be used".to_string())
+            }
+
+            if run.all {
+                if run.day.is_some() || run.part.is_some() || run.input.is_some() || run.text.is_some() {
+                    return Err("--all runs whole years, so takes no day, part or input".to_string())
+                }
+            } else if run.year.is_none() {
+                return Err("Missing year".to_string())
+            } else if run.day.is_none() {
+                return Err("Missing day".to_string())
+            }
+
+            Ok(Command::Run(run))
+        },
+        Some("bench") => {
+            let positional = positional_args(&args[1..], 2)?;
+
+            let mut bench = BenchOptions {
+                year: positional.first().map(|year| parse_number(Some(*year), "year")).transpose()?,
+                day: positional.get(1).map(|day| parse_number(Some(*day), "day")).transpose()?,
+                part: None,
+                runs: 20,
+                warmup: 3,
+                save: None,
+                compare: None,
+                threshold: DEFAULT_THRESHOLD,
+            };
+
+            let mut options = args.iter().skip(1 + positional.len());
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--part" => bench.part = Some(parse_number(options.next(), "part")?),
+                    "--runs" => bench.runs = parse_number(options.next(), "run count")?,
+                    "--warmup" => bench.warmup = parse_number(options.next(), "warmup count")?,
+                    "--save" => bench.save = Some(PathBuf::from(options.next().ok_or("Missing save path")?)),
+                    "--compare" => bench.compare = Some(PathBuf::from(options.next().ok_or("Missing baseline path")?)),
+                    "--threshold" => bench.threshold = parse_number(options.next(), "threshold")?,
+                    _ => return Err(format!("Unknown option: {}", option)),
+                }
+            }
+
+            if bench.runs == 0 {
+                return Err("Need at least one run".to_string())
+            }
+            if bench.part.is_some() && bench.day.is_none() {
+                return Err("--part needs a day".to_string())
+            }
+
+            Ok(Command::Bench(bench))
+        },
+        Some("fetch") => {
+            let positional = positional_args(&args[1..], 2)?;
+            if let Some(option) = args.get(1 + positional.len()) {
+                return Err(format!("Unknown option: {}", option))
+            }
+
+            Ok(Command::Fetch {
+                year: parse_number(positional.first().copied(), "year")?,
+                day: parse_number(positional.get(1).copied(), "day")?,
+            })
+        },
+        Some("submit") => {
+            let positional = positional_args(&args[1..], 4)?;
+
+            let mut submit = SubmitOptions {
+                year: parse_number(positional.first().copied(), "year")?,
+                day: parse_number(positional.get(1).copied(), "day")?,
+                part: parse_number(positional.get(2).copied(), "part")?,
+                answer: positional.get(3).map(|answer| answer.to_string()),
+                answers: answers::default_path(),
+                ledger: submit::default_ledger_path(),
+            };
+
+            let mut options = args.iter().skip(1 + positional.len());
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--answers" => submit.answers = PathBuf::from(options.next().ok_or("Missing answers path")?),
+                    "--ledger" => submit.ledger = PathBuf::from(options.next().ok_or("Missing ledger path")?),
+                    _ => return Err(format!("Unknown option: {}", option)),
+                }
+            }
+
+            Ok(Command::Submit(submit))
+        },
+        Some("render") => {
+            let positional = positional_args(&args[1..], 2)?;
+            let day = parse_number(positional.get(1).copied(), "day")?;
+
+            let mut render = RenderOptions {
+                year: parse_number(positional.first().copied(), "year")?,
+                day,
+                part: 1,
+                output: PathBuf::from(format!("day{}.gif", day)),
+                scale: 4,
+                every: 1,
+                delay: Duration::from_millis(50),
+            };
+
+            let mut options = args.iter().skip(1 + positional.len());
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--part" => render.part = parse_number(options.next(), "part")?,
+                    "--output" => render.output = PathBuf::from(options.next().ok_or("Missing output path")?),
+                    "--scale" => render.scale = parse_number(options.next(), "scale")?,
+                    "--every" => render.every = parse_number(options.next(), "frame step")?,
+                    "--delay" => render.delay = Duration::from_millis(parse_number(options.next(), "delay")?),
+                    _ => return Err(format!("Unknown option: {}", option)),
+                }
+            }
+            if render.scale == 0 || render.every == 0 {
+                return Err("--scale and --every need to be at least 1".to_string())
+            }
+
+            Ok(Command::Render(render))
+        },
+        Some("watch") => {
+            let positional = positional_args(&args[1..], 2)?;
+            let (mut part, mut fps) = (1, 30);
+
+            let mut options = args.iter().skip(1 + positional.len());
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--part" => part = parse_number(options.next(), "part")?,
+                    "--fps" => fps = parse_number(options.next(), "frame rate")?,
+                    _ => return Err(format!("Unknown option: {}", option)),
+                }
+            }
+
+            Ok(Command::Watch {
+                year: parse_number(positional.first().copied(), "year")?,
+                day: parse_number(positional.get(1).copied(), "day")?,
+                part,
+                fps,
+            })
+        },
+        Some("inputs") => {
+            let action = match args.get(1).map(String::as_str) {
+                Some("key") => InputsAction::Key,
+                Some("encrypt") => InputsAction::Encrypt,
+                Some("decrypt") => InputsAction::Decrypt,
+                Some(action) => return Err(format!("Unknown inputs command: {}", action)),
+                None => return Err("Missing inputs command".to_string()),
+            };
+            let positional = positional_args(&args[2..], 1)?;
+
+            let mut inputs = InputsOptions {
+                action,
+                year: positional.first().map(|year| parse_number(Some(*year), "year")).transpose()?,
+                keep: false,
+            };
+
+            for option in args.iter().skip(2 + positional.len()) {
+                match option.as_str() {
+                    "--keep" => inputs.keep = true,
+                    _ => return Err(format!("Unknown option: {}", option)),
+                }
+            }
+
+            Ok(Command::Inputs(inputs))
+        },
+        Some(command) => Err(format!("Unknown command: {}", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+fn print_banner(year: u16) {
+    println!("         .     .  .      +     .      .          .");
+    println!("     .       .      .     #       .           .");
+    println!("        .      .         ###            .      .      .");
+    println!("      .      .   \"#:. .:##\"##:. .:#\"  .      .");
+    println!("          .      . \"####\"###\"####\"  .");
+    println!("       .     \"#:.    .:#\"###\"#:.    .:#\"  .        .       .");
+    println!("  .             \"#########\"#########\"        .        .");
+    println!("        .    \"#:.  \"####\"###\"####\"  .:#\"   .       .");
+    println!("     .     .  \"#######\"\"##\"##\"\"#######\"                  .");
+    println!("                .\"##\"#####\"#####\"##\"           .      .");
+    println!("    .   \"#:. ...  .:##\"###\"###\"##:.  ... .:#\"     .");
+    println!("      .     \"#######\"##\"#####\"##\"#######\"      .     .");
+    println!("    .    .     \"#####\"\"#######\"\"#####\"    .      .");
+    println!("            .     \"      000      \"    .     .");
+    println!("       .         .   .   000     .        .       .");
+    println!(".. .. ..................O000O........................ ...... ...");
+    println!("... .. .......... Advent of Code {} ................... ... ..\n", year);
+}
+
+fn find_year(years: &[Year], year: u16) -> Result<&Year, String> {
+    years.iter()
+        .find(|candidate| candidate.year == year)
+        .ok_or(format!("No solutions for {}", year))
+}
+
+/// Reads the input and solves the given parts, returning the id of the input along with the results. An unreadable
+/// input is reported as a failure of every part so that running everything can carry on with the other days.
+fn solve_puzzle(puzzle: &Puzzle, input_path: &Path, parts: &[u8]) -> (PuzzleRun, Option<String>) {
+    match read_input(input_path) {
+        Ok(input) => (puzzle.solve(&input, parts), Some(answers::input_id(&input))),
+        Err(message) => {
+            let run = PuzzleRun {
+                parse_time: Duration::ZERO,
+                parts: parts.iter()
+                    .map(|part| PartRun { part: *part, answer: Err(message.clone()), solve_time: Duration::ZERO })
+                    .collect(),
+            };
+
+            (run, None)
+        },
+    }
+}
+
+fn read_stdin() -> Result<String, String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).map_err(|err| format!("Unable to read stdin: {}", err))?;
+
+    Ok(input)
+}
+
+/// Whose inputs to run the given day with, and where each of them lives. When running everyone's inputs, or one
+/// person's inputs for a whole year, anyone without an input for the day is left out.
+fn input_sources(year: &Year, day: u8, options: &RunOptions) -> Vec<(String, PathBuf)> {
+    if let Some(input) = &options.input {
+        return vec![(DEFAULT_USER.to_string(), input.clone())]
+    }
+    if let Some(user) = &options.user {
+        let path = user_input_path(&options.users_dir, user, year.year, day);
+        if options.all && !input_exists(&path) {
+            return Vec::new()
+        }
+
+        return vec![(user.clone(), path)]
+    }
+
+    let mut sources = vec![(DEFAULT_USER.to_string(), year.input_path(day))];
+
+    if options.all_users {
+        sources.extend(users(&options.users_dir).into_iter()
+            .map(|user| {
+                let path = user_input_path(&options.users_dir, &user, year.year, day);
+                (user, path)
+            }));
+        sources.retain(|(_, path)| input_exists(path));
+    }
+
+    sources
+}
+
+/// Checks each part's answer against the manifest, recording any answers it doesn't know about yet if asked to.
+/// Returns the status of each part, in the same order as the parts, and how many answers were recorded.
+fn check_answers(answers: &mut Answers, record: bool, year: u16, day: u8, input_id: Option<&str>,
+                 run: &PuzzleRun) -> (Vec<Status>, usize) {
+    let mut recorded = 0;
+
+    let statuses = run.parts.iter()
+        .map(|part| {
+            let input_id = match input_id {
+                Some(input_id) => input_id,
+                None => return Status::Error,
+            };
+
+            let status = Status::check(&part.answer, answers.expected(year, day, part.part, input_id));
+
+            if let (Status::Unknown, true, Ok(answer)) = (&status, record, &part.answer) {
+                answers.record(year, day, part.part, input_id, answer);
+                recorded += 1;
+            }
+
+            status
+        })
+        .collect();
+
+    (statuses, recorded)
+}
+
+/// Saves the manifest if anything was added to it
+fn save_answers(answers: &Answers, recorded: usize) -> Result<(), String> {
+    if recorded > 0 {
+        answers.save()?;
+        println!("\nRecorded {} new {}", recorded, if recorded == 1 { "answer" } else { "answers" });
+    }
+
+    Ok(())
+}
+
+/// Runs the given parts of a puzzle and prints the answers in the same style the old per-year binaries used
+fn run_day(years: &[Year], options: &RunOptions) -> Result<(), String> {
+    let year = find_year(years, options.year.unwrap())?;
+    let day = options.day.unwrap();
+    let puzzle = year.puzzle(day).ok_or(format!("Day {} not implemented yet", day))?;
+
+    let parts = match options.part {
+        Some(part) if part < 1 || part > puzzle.part_count => return Err(format!("Day {} has no part {}", day, part)),
+        Some(part) => vec![part],
+        None => puzzle.parts(),
+    };
+
+    let inputs = match (&options.text, &options.input) {
+        (Some(text), _) => vec![(DEFAULT_USER.to_string(), text.clone())],
+        (None, Some(path)) if path.as_os_str() == "-" => vec![(DEFAULT_USER.to_string(), read_stdin()?)],
+        _ => {
+            let sources = input_sources(year, day, options);
+            if sources.is_empty() {
+                return Err(format!("Nobody has an input for {} day {}", year.year, day))
+            }
+
+            sources.into_iter()
+                .map(|(user, path)| read_input(&path).map(|input| (user, input)))
+                .collect::<Result<Vec<_>, _>>()?
+        },
+    };
+
+    let mut answers = Answers::load(&options.answers)?;
+    let mut recorded = 0;
+    let mut failed = false;
+
+    print_banner(year.year);
+
+    for (user, input) in inputs {
+        let input_id = answers::input_id(&input);
+
+        let title = if options.all_users {
+            format!("--- Day {}: {} ({}) ---", puzzle.day, puzzle.title, user)
+        } else {
+            format!("--- Day {}: {} ---", puzzle.day, puzzle.title)
+        };
+        println!("{}", title);
+
+        let run = puzzle.solve(&input, &parts);
+        let (statuses, day_recorded) = check_answers(&mut answers, options.record, year.year, day, Some(&input_id),
+                                                     &run);
+        recorded += day_recorded;
+
+        for (part, status) in run.parts.iter().zip(&statuses) {
+            let part_label = format!("--- Part {}", part.part);
+            println!("{:width$}---", part_label, width = title.len() - 3);
+
+            match (&part.answer, status) {
+                (Ok(answer), Status::Correct) => println!(">>>> {} (correct)", answer),
+                (Ok(answer), Status::Wrong { expected }) => println!(">>>> {} (wrong, expected {})", answer, expected),
+                (Ok(answer), _) => println!(">>>> {} (not verified)", answer),
+                (Err(message), _) => println!("!!!! {}", message),
+            }
+        }
+
+        println!("\nParse Time:   {:.4} ms", run.parse_time.as_nanos() as f64 / 1_000_000.0);
+        println!("Elapsed Time: {:.4} ms\n", run.total_time().as_nanos() as f64 / 1_000_000.0);
+
+        failed |= statuses.iter().any(Status::is_failure);
+    }
+
+    save_answers(&answers, recorded)?;
+
+    if failed {
+        return Err(format!("Day {} failed", puzzle.day))
+    }
+
+    Ok(())
+}
+
+/// Runs every day of the selected years and prints a summary of the results
+fn run_all(years: &[Year], options: &RunOptions) -> Result<(), String> {
+    let selected: Vec<&Year> = match options.year {
+        Some(year) => vec![find_year(years, year)?],
+        None => years.iter().collect(),
+    };
+
+    let mut answers = Answers::load(&options.answers)?;
+    let mut recorded = 0;
+
+    // Failures are reported in the summary, so keep panic messages from cluttering the output
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let results: Vec<DayResult> = selected.iter()
+        .flat_map(|year| year.puzzles.iter().map(move |puzzle| (year, puzzle)))
+        .flat_map(|(year, puzzle)| {
+            input_sources(year, puzzle.day, options).into_iter().map(move |(user, path)| (year, puzzle, user, path))
+        })
+        .map(|(year, puzzle, user, input_path)| {
+            let (run, input_id) = solve_puzzle(puzzle, &input_path, &puzzle.parts());
+            let (statuses, day_recorded) = check_answers(&mut answers, options.record, year.year, puzzle.day,
+                                                         input_id.as_deref(), &run);
+            recorded += day_recorded;
+
+            DayResult { user, year: year.year, day: puzzle.day, run, statuses }
+        })
+        .collect();
+
+    panic::set_hook(default_hook);
+
+    report::print(&results, options.format);
+
+    // Keep the JSON and CSV output machine readable
+    if options.format == Format::Table {
+        save_answers(&answers, recorded)?;
+    } else if recorded > 0 {
+        answers.save()?;
+    }
+
+    if results.iter().any(|result| result.statuses.iter().any(Status::is_failure)) {
+        return Err("Some parts failed".to_string())
+    }
+
+    Ok(())
+}
+
+/// Times a single part over and over, after a few untimed warmup runs. Only the solve time is measured so that
+/// changes to a part aren't drowned out by the time spent parsing.
+fn bench_part(puzzle: &Puzzle, input: &str, part: u8, options: &BenchOptions) -> Result<Stats, String> {
+    let mut samples = Vec::with_capacity(options.runs);
+
+    for run in 0..options.warmup + options.runs {
+        let result = puzzle.solve(input, &[part]).parts.remove(0);
+        result.answer.map_err(|message| format!("Day {} part {} failed: {}", puzzle.day, part, message))?;
+
+        if run >= options.warmup {
+            samples.push(result.solve_time);
+        }
+    }
+
+    Ok(Stats::from_samples(&samples))
+}
+
+fn bench(years: &[Year], options: &BenchOptions) -> Result<(), String> {
+    let selected: Vec<&Year> = match options.year {
+        Some(year) => vec![find_year(years, year)?],
+        None => years.iter().collect(),
+    };
+
+    let baseline = options.compare.as_deref().map(bench::load_baseline).transpose()?;
+
+    let as_ms = |duration: Duration| duration.as_nanos() as f64 / 1_000_000.0;
+    let mut results: Vec<(BenchKey, Stats)> = vec![];
+    let mut regressions = 0;
+
+    println!("Year  Day  Part    Min (ms)  Median (ms)   Mean (ms)  Stddev (ms){}",
+             if baseline.is_some() { "    Change" } else { "" });
+
+    for year in selected {
+        let puzzles: Vec<&Puzzle> = match options.day {
+            Some(day) => vec![year.puzzle(day).ok_or(format!("Day {} not implemented yet", day))?],
+            None => year.puzzles.iter().collect(),
+        };
+
+        for puzzle in puzzles {
+            let input = year.read_input(puzzle.day)?;
+
+            let parts = match options.part {
+                Some(part) if part < 1 || part > puzzle.part_count => {
+                    return Err(format!("Day {} has no part {}", puzzle.day, part))
+                },
+                Some(part) => vec![part],
+                None => puzzle.parts(),
+            };
+
+            for part in parts {
+                let key = (year.year, puzzle.day, part);
+                let stats = bench_part(puzzle, &input, part, options)?;
+
+                let change = match baseline.as_ref().and_then(|baseline| baseline.get(&key)) {
+                    Some(saved) => match bench::percent_change(saved, &stats) {
+                        Some(change) => {
+                            let regressed = change > options.threshold;
+                            if regressed {
+                                regressions += 1;
+                            }
+
+                            format!("  {:+7.1}%{}", change, if regressed { "  REGRESSION" } else { "" })
+                        },
+                        // A zero baseline median has nothing to measure a change against
+                        None => "       n/a".to_string(),
+                    },
+                    None if baseline.is_some() => "       new".to_string(),
+                    None => String::new(),
+                };
+
+                println!("{:4}  {:3}  {:4}  {:10.3}  {:11.3}  {:10.3}  {:11.3}{}", year.year, puzzle.day, part,
+                         as_ms(stats.min), as_ms(stats.median), as_ms(stats.mean), as_ms(stats.stddev), change);
+
+                results.push((key, stats));
+            }
+        }
+    }
+
+    if let Some(path) = &options.save {
+        bench::save_baseline(path, &results)?;
+        println!("\nSaved baseline to {}", path.display());
+    }
+
+    if regressions > 0 {
+        return Err(format!("{} {} slower than the baseline by more than {}%", regressions,
+                           if regressions == 1 { "part is" } else { "parts are" }, options.threshold))
+    }
+
+    Ok(())
+}
+
+/// Encrypts or decrypts the inputs of the selected years, or generates a key to do it with
+fn manage_inputs(years: &[Year], options: &InputsOptions) -> Result<(), String> {
+    if let InputsAction::Key = options.action {
+        println!("{}", Key::generate()?.to_hex());
+        eprintln!("Keep this somewhere safe, then save it in {}, or set {} to it or {} to a file holding it",
+                  crypt::default_key_path().display(), crypt::KEY_VAR, crypt::KEY_FILE_VAR);
+        return Ok(())
+    }
+
+    let selected: Vec<(u16, PathBuf)> = match options.year {
+        Some(year) => vec![find_year(years, year)?],
+        None => years.iter().collect(),
+    }.into_iter().map(|year| (year.year, year.input_dir.clone())).collect();
+
+    let dirs = inputs::input_dirs(&selected, &users_dir());
+    let key = Key::required()?;
+
+    match options.action {
+        InputsAction::Encrypt => inputs::encrypt(&dirs, &key, options.keep),
+        InputsAction::Decrypt => inputs::decrypt(&dirs, &key),
+        InputsAction::Key => Ok(()),
+    }
+}
+
+/// Downloads a day's input into the year's input directory, if it isn't there already. It's saved encrypted when an
+/// input key is configured.
+fn fetch(years: &[Year], year: u16, day: u8) -> Result<(), String> {
+    let path = find_year(years, year)?.input_path(day);
+    let key = Key::from_env()?;
+
+    match site::fetch(site::Site::from_env, year, day, &path, key.as_ref(), SystemTime::now())? {
+        Some(written) => println!("Downloaded {}", written.display()),
+        None => println!("Already have {}", path.display()),
+    }
+
+    Ok(())
+}
+
+/// Sends an answer to the site, solving the part first if no answer was given
+fn submit_answer(years: &[Year], options: &SubmitOptions) -> Result<(), String> {
+    let year = find_year(years, options.year)?;
+    let puzzle = year.puzzle(options.day).ok_or(format!("Day {} not implemented yet", options.day))?;
+    if options.part < 1 || options.part > puzzle.part_count {
+        return Err(format!("Day {} has no part {}", options.day, options.part))
+    }
+
+    let input = year.read_input(options.day)?;
+    let input_id = answers::input_id(&input);
+
+    let answer = match &options.answer {
+        Some(answer) => answer.clone(),
+        None => puzzle.solve(&input, &[options.part]).parts.remove(0).answer
+            .map_err(|message| format!("Part {} failed: {}", options.part, message))?,
+    };
+    println!("Submitting {} for {} day {} part {}", answer, year.year, options.day, options.part);
+
+    let mut ledger = submit::Ledger::load(&options.ledger)?;
+    let mut answers = Answers::load(&options.answers)?;
+    let submission = submit::Submission {
+        year: year.year,
+        day: options.day,
+        part: options.part,
+        input_id: &input_id,
+        answer: &answer,
+    };
+
+    let verdict = submit::submit(site::Site::from_env, &mut ledger, &mut answers, &submission, SystemTime::now())?;
+    println!("{}", verdict.message);
+
+    match verdict.outcome {
+        submit::Outcome::Correct => Ok(()),
+        outcome => Err(format!("Not accepted: {}", outcome.label())),
+    }
+}
+
+/// The function that plays out a day's simulation, and the input to play it with
+fn frames_and_input(years: &[Year], year: u16, day: u8, part: u8) -> Result<(Frames, String), String> {
+    let year = find_year(years, year)?;
+    let puzzle = year.puzzle(day).ok_or(format!("Day {} not implemented yet", day))?;
+    if part < 1 || part > puzzle.part_count {
+        return Err(format!("Day {} has no part {}", day, part))
+    }
+    let frames = puzzle.frames.ok_or(format!("Day {} has nothing to render", day))?;
+
+    Ok((frames, year.read_input(day)?))
+}
+
+/// Plays a day's simulation and draws it as an animation, a directory of frames or a single picture of how it ends
+fn render_frames(years: &[Year], options: &RenderOptions) -> Result<(), String> {
+    let (frames, input) = frames_and_input(years, options.year, options.day, options.part)?;
+
+    let (mut sink, every) = match options.output.extension().and_then(|extension| extension.to_str()) {
+        Some("gif") => (FrameSink::Gif(None), options.every),
+        Some("png") | Some("ppm") => (FrameSink::Last(None), usize::MAX),
+        None => (FrameSink::Sequence(PngSequence::create(&options.output)?), options.every),
+        Some(_) => return Err(format!("Can't render to {}: use .gif, .png, .ppm or a directory",
+                                      options.output.display())),
+    };
+
+    let draw = |grid: &Grid<char>| Image::from_grid(grid, options.scale, |cell| render::char_colour(*cell));
+
+    let mut count: usize = 0;
+    // The latest frame that was skipped, so the animation can still finish on the last step
+    let mut skipped: Option<Grid<char>> = None;
+    let mut failure: Option<String> = None;
+
+    frames(&input, options.part, &mut |grid| {
+        if failure.is_some() {
+            return
+        }
+
+        if count.is_multiple_of(every) {
+            skipped = None;
+            if let Err(message) = sink.add(draw(grid), options) {
+                failure = Some(message);
+            }
+        } else {
+            skipped = Some(grid.clone());
+        }
+        count += 1;
+    });
+
+    if let Some(message) = failure {
+        return Err(message)
+    }
+    if let Some(grid) = skipped {
+        sink.add(draw(&grid), options)?;
+    }
+
+    match sink {
+        FrameSink::Last(Some(image)) => image.save(&options.output)?,
+        FrameSink::Last(None) | FrameSink::Gif(None) => return Err(format!("Day {} gave no frames", options.day)),
+        FrameSink::Gif(Some(_)) | FrameSink::Sequence(_) => {},
+    }
+    println!("Rendered {} steps to {}", count, options.output.display());
+
+    Ok(())
+}
+
+/// Plays a day's simulation in the terminal, until it ends and the user quits
+fn watch(years: &[Year], year: u16, day: u8, part: u8, fps: u32) -> Result<(), String> {
+    let (frames, input) = frames_and_input(years, year, day, part)?;
+    if !io::stdout().is_terminal() {
+        return Err("watch needs to be run in a terminal".to_string())
+    }
+
+    let mut viewer = Viewer::start(fps)?;
+    let mut failure: Option<String> = None;
+
+    frames(&input, part, &mut |grid| {
+        if failure.is_none() && !viewer.quit() {
+            failure = viewer.show(grid).err();
+        }
+    });
+
+    match failure {
+        Some(message) => Err(message),
+        None => viewer.finish(),
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let mut years = years();
+
+    if let Some(root) = &cli.input_root {
+        for year in &mut years {
+            year.input_dir = root_input_dir(root, year.year);
+        }
+    }
+
+    match cli.command {
+        Command::List => {
+            for year in &years {
+                println!("{}", year.year);
+
+                for puzzle in &year.puzzles {
+                    println!("  Day {:>2}: {} ({} {})", puzzle.day, puzzle.title, puzzle.part_count,
+                             if puzzle.part_count == 1 { "part" } else { "parts" });
+                }
+            }
+        },
+        Command::Run(options) if options.all => run_all(&years, &options)?,
+        Command::Run(options) => run_day(&years, &options)?,
+        Command::Bench(options) => bench(&years, &options)?,
+        Command::Inputs(options) => manage_inputs(&years, &options)?,
+        Command::Fetch { year, day } => fetch(&years, year, day)?,
+        Command::Submit(options) => submit_answer(&years, &options)?,
+        Command::Render(options) => render_frames(&years, &options)?,
+        Command::Watch { year, day, part, fps } => watch(&years, year, day, part, fps)?,
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let cli = match parse_args(&args) {
+        Ok(cli) => cli,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(message) = run(cli) {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
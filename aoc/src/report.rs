@@ -0,0 +1,267 @@
+//! Summaries of a run-all: a table for people, plus JSON and CSV for dashboards.
+
+use std::time::Duration;
+
+use aoc_common::{PartRun, PuzzleRun};
+
+use crate::answers::Status;
+
+/// How many of the slowest days to list in the table footer and the JSON summary
+const SLOWEST_DAY_COUNT: usize = 5;
+
+/// The output formats supported when running every day
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Format, String> {
+        match value {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format: {}", value)),
+        }
+    }
+}
+
+/// Everything that happened when running a single day
+pub struct DayResult {
+    /// Whose input the day was run with
+    pub user: String,
+    pub year: u16,
+    pub day: u8,
+    pub run: PuzzleRun,
+    /// How each part's answer compares with the answers manifest, in the same order as the parts
+    pub statuses: Vec<Status>,
+}
+
+impl DayResult {
+    /// The year and day, along with the user when the results cover more than one person's inputs
+    fn label(&self, show_user: bool) -> String {
+        if show_user {
+            format!("{} day {:2} ({})", self.year, self.day, self.user)
+        } else {
+            format!("{} day {:2}", self.year, self.day)
+        }
+    }
+
+    fn parts(&self) -> std::iter::Zip<std::slice::Iter<'_, PartRun>, std::slice::Iter<'_, Status>> {
+        self.run.parts.iter().zip(&self.statuses)
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+/// The days that took the longest overall, slowest first
+fn slowest_days(results: &[DayResult]) -> Vec<&DayResult> {
+    let mut slowest: Vec<&DayResult> = results.iter().collect();
+    slowest.sort_by_key(|result| std::cmp::Reverse(result.run.total_time()));
+    slowest.truncate(SLOWEST_DAY_COUNT);
+
+    slowest
+}
+
+struct Totals {
+    parts: usize,
+    passed: usize,
+    failed: usize,
+    parse_time: Duration,
+    solve_time: Duration,
+}
+
+fn totals(results: &[DayResult]) -> Totals {
+    let all_parts = results.iter().flat_map(DayResult::parts);
+
+    Totals {
+        parts: all_parts.clone().count(),
+        passed: all_parts.clone().filter(|(_, status)| **status == Status::Correct).count(),
+        failed: all_parts.clone().filter(|(_, status)| status.is_failure()).count(),
+        parse_time: results.iter().map(|result| result.run.parse_time).sum(),
+        solve_time: all_parts.map(|(part, _)| part.solve_time).sum(),
+    }
+}
+
+pub fn print(results: &[DayResult], format: Format) {
+    match format {
+        Format::Table => print_table(results),
+        Format::Json => print_json(results),
+        Format::Csv => print_csv(results),
+    }
+}
+
+/// Whether the results come from more than one person's inputs
+fn has_several_users(results: &[DayResult]) -> bool {
+    results.iter().any(|result| result.user != results[0].user)
+}
+
+fn print_table(results: &[DayResult]) {
+    let show_users = has_several_users(results);
+    let user_width = results.iter().map(|result| result.user.len()).max().unwrap_or(0).max("User".len());
+    let user_column = |user: &str| if show_users { format!("{:user_width$}  ", user) } else { String::new() };
+
+    let answer_width = results.iter()
+        .flat_map(|result| result.run.parts.iter())
+        .map(|part| part.answer.as_ref().map(String::len).unwrap_or(1))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    let header = format!("{}Year  Day  Part  {:answer_width$}  Status  Parse (ms)  Solve (ms)",
+                         user_column("User"), "Answer");
+    let separator = "-".repeat(header.len());
+
+    println!("{}", header);
+    println!("{}", separator);
+
+    for result in results {
+        for (part, status) in result.parts() {
+            println!("{}{:4}  {:3}  {:4}  {:answer_width$}  {:6}  {:10.3}  {:10.3}",
+                     user_column(&result.user), result.year, result.day, part.part,
+                     part.answer.as_deref().unwrap_or("-"), status.label(),
+                     as_ms(result.run.parse_time), as_ms(part.solve_time));
+        }
+    }
+
+    let totals = totals(results);
+    println!("{}", separator);
+    println!("{} parts: {} passed, {} failed, {} new | parse {:.3} ms | solve {:.3} ms | total {:.3} ms",
+             totals.parts, totals.passed, totals.failed, totals.parts - totals.passed - totals.failed,
+             as_ms(totals.parse_time),
+             as_ms(totals.solve_time), as_ms(totals.parse_time + totals.solve_time));
+
+    println!("\nSlowest days:");
+    for result in slowest_days(results) {
+        println!("  {}  {:10.3} ms", result.label(show_users), as_ms(result.run.total_time()));
+    }
+
+    let failures: Vec<(&DayResult, &PartRun, String)> = results.iter()
+        .flat_map(|result| result.parts().map(move |(part, status)| (result, part, status)))
+        .filter_map(|(result, part, status)| match (status, &part.answer) {
+            (Status::Wrong { expected }, Ok(answer)) => {
+                Some((result, part, format!("answered {} but expected {}", answer, expected)))
+            },
+            (_, Err(message)) => Some((result, part, message.clone())),
+            _ => None,
+        })
+        .collect();
+
+    if !failures.is_empty() {
+        println!("\nFailures:");
+        for (result, part, message) in failures {
+            println!("  {} part {}: {}", result.label(show_users), part.part, message.replace('\n', " "));
+        }
+    }
+}
+
+/// Escapes a string for use inside a JSON string literal
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+fn print_json(results: &[DayResult]) {
+    let rows: Vec<String> = results.iter()
+        .flat_map(|result| result.parts().map(move |(part, status)| (result, part, status)))
+        .map(|(result, part, status)| {
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (json_string(answer), "null".to_string()),
+                Err(message) => ("null".to_string(), json_string(message)),
+            };
+            let expected = match status {
+                Status::Correct => answer.clone(),
+                Status::Wrong { expected } => json_string(expected),
+                _ => "null".to_string(),
+            };
+
+            format!("    {{\"user\": {}, \"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"expected\": {}, \
+                     \"status\": \"{}\", \"parse_ms\": {:.6}, \"solve_ms\": {:.6}, \"error\": {}}}",
+                    json_string(&result.user), result.year, result.day, part.part, answer, expected, status.label(),
+                    as_ms(result.run.parse_time), as_ms(part.solve_time), error)
+        })
+        .collect();
+
+    let slowest: Vec<String> = slowest_days(results).iter()
+        .map(|result| format!("    {{\"user\": {}, \"year\": {}, \"day\": {}, \"total_ms\": {:.6}}}",
+                              json_string(&result.user), result.year, result.day, as_ms(result.run.total_time())))
+        .collect();
+
+    let totals = totals(results);
+
+    println!("{{");
+    println!("  \"results\": [\n{}\n  ],", rows.join(",\n"));
+    println!("  \"totals\": {{\"parts\": {}, \"passed\": {}, \"failed\": {}, \"new\": {}, \"parse_ms\": {:.6}, \
+              \"solve_ms\": {:.6}}},",
+             totals.parts, totals.passed, totals.failed, totals.parts - totals.passed - totals.failed,
+             as_ms(totals.parse_time), as_ms(totals.solve_time));
+    println!("  \"slowest\": [\n{}\n  ]", slowest.join(",\n"));
+    println!("}}");
+}
+
+/// Quotes a CSV field when it contains anything that would otherwise break the row apart
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn print_csv(results: &[DayResult]) {
+    println!("user,year,day,part,answer,expected,status,parse_ms,solve_ms,error");
+
+    for result in results {
+        for (part, status) in result.parts() {
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (csv_field(answer), String::new()),
+                Err(message) => (String::new(), csv_field(message)),
+            };
+            let expected = match status {
+                Status::Correct => answer.clone(),
+                Status::Wrong { expected } => csv_field(expected),
+                _ => String::new(),
+            };
+
+            println!("{},{},{},{},{},{},{},{:.6},{:.6},{}", csv_field(&result.user), result.year, result.day, part.part,
+                     answer, expected, status.label(), as_ms(result.run.parse_time), as_ms(part.solve_time), error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, json_string};
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("1234"), "\"1234\"");
+        assert_eq!(json_string("left: \"a\"\nright: \\b"), "\"left: \\\"a\\\"\\nright: \\\\b\"");
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
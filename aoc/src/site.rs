@@ -0,0 +1,343 @@
+//! Talking to the Advent of Code site, for `aoc fetch` and `aoc submit`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::crypt::{self, Key};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable pointing at a different site, such as a stand-in for testing
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the session token
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Identifies the runner to the site, which asks that automated requests say where they come from
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (+https://github.com/aldryd/rust-aoc)");
+
+/// Puzzles unlock at midnight in UTC-5
+const UNLOCK_OFFSET: Duration = Duration::from_secs(5 * 60 * 60);
+
+/// What came back from a request
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Makes HTTP requests on behalf of the site client
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+
+    /// Posts the fields as a URL encoded form
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// Real HTTP requests, over TLS where the URL asks for it
+pub struct UreqHttp {
+    agent: ureq::Agent,
+}
+
+impl UreqHttp {
+    pub fn new() -> UreqHttp {
+        UreqHttp { agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build() }
+    }
+}
+
+impl UreqHttp {
+    /// Turns what ureq gives back into a response, treating error statuses as responses rather than failures
+    fn response(url: &str, result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(format!("Unable to reach {}: {}", url, err)),
+        };
+
+        let status = response.status();
+        let body = response.into_string().map_err(|err| format!("Unable to read the response from {}: {}", url, err))?;
+
+        Ok(Response { status, body })
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        UreqHttp::response(url, self.agent.get(url).set("Cookie", &format!("session={}", session)).call())
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        UreqHttp::response(url, self.agent.post(url).set("Cookie", &format!("session={}", session)).send_form(form))
+    }
+}
+
+/// Where the session token is kept when it isn't in the environment
+fn session_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+
+    Some(config_dir.join("aoc").join("session"))
+}
+
+/// The session token from `AOC_SESSION` or the session file
+pub fn session() -> Result<String, String> {
+    if let Some(session) = std::env::var(SESSION_VAR).ok().filter(|session| !session.trim().is_empty()) {
+        return Ok(session.trim().to_string())
+    }
+
+    let path = session_path().ok_or(format!("No session token given; set {}", SESSION_VAR))?;
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(format!("No session token given; set {} or put it in {}", SESSION_VAR, path.display())),
+    }
+}
+
+/// The number of days from 1970-01-01 to the given date, using the proleptic Gregorian calendar
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// When the given day's puzzle unlocks
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_since_epoch(year as i64, 12, day as i64) as u64;
+
+    UNIX_EPOCH + Duration::from_secs(days * 24 * 60 * 60) + UNLOCK_OFFSET
+}
+
+/// Fails unless the given day's puzzle has unlocked by `now`
+pub fn check_unlocked(year: u16, day: u8, now: SystemTime) -> Result<(), String> {
+    if year < 2015 || !(1..=25).contains(&day) {
+        return Err(format!("There is no puzzle for {} day {}", year, day))
+    }
+
+    match unlock_time(year, day).duration_since(now) {
+        Ok(wait) if !wait.is_zero() => {
+            let minutes = wait.as_secs().div_ceil(60);
+            Err(format!("{} day {} unlocks in {}h {:02}m", year, day, minutes / 60, minutes % 60))
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Whether the input has already been downloaded, in either its plain or encrypted form
+pub fn is_cached(path: &Path) -> bool {
+    path.exists() || crypt::encrypted_path(path).exists()
+}
+
+/// A client for the site, logged in with a session token
+pub struct Site {
+    http: Box<dyn Http>,
+    base_url: String,
+    session: String,
+}
+
+impl Site {
+    pub fn new(http: Box<dyn Http>, base_url: &str, session: &str) -> Site {
+        Site { http, base_url: base_url.trim_end_matches('/').to_string(), session: session.to_string() }
+    }
+
+    /// A client for the real site, or for the one named by `AOC_BASE_URL`, using the configured session token
+    pub fn from_env() -> Result<Site, String> {
+        let base_url = std::env::var(BASE_URL_VAR).ok().filter(|url| !url.is_empty());
+
+        Ok(Site::new(Box::new(UreqHttp::new()), base_url.as_deref().unwrap_or(BASE_URL), &session()?))
+    }
+
+    /// The body of a successful response, or an error saying what went wrong
+    fn body(url: &str, year: u16, day: u8, response: Response) -> Result<String, String> {
+        match response.status {
+            200 => Ok(response.body),
+            400 | 401 | 403 => Err(format!("The site didn't accept the session token ({})", response.status)),
+            404 => Err(format!("{} day {} isn't available yet", year, day)),
+            status => Err(format!("Unable to fetch {}: {} {}", url, status, response.body.lines().next().unwrap_or(""))),
+        }
+    }
+
+    /// Downloads the given day's input
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        Site::body(&url, year, day, self.http.get(&url, &self.session)?)
+    }
+
+    /// Posts an answer, returning the page the site responds with
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let form = [("level", &*part.to_string()), ("answer", answer)];
+
+        Site::body(&url, year, day, self.http.post(&url, &self.session, &form)?)
+    }
+}
+
+/// Downloads the given day's input to `path`, unless it's already there. With a key, only the encrypted copy is
+/// written, so the plain input never touches the disk; without one, the plain file is written, which git ignores.
+/// The site is only connected to, and the session token only looked up, when a download is needed. Returns the file
+/// that was written, or `None` if the input was already there.
+pub fn fetch(site: impl FnOnce() -> Result<Site, String>, year: u16, day: u8, path: &Path, key: Option<&Key>,
+             now: SystemTime) -> Result<Option<PathBuf>, String> {
+    if is_cached(path) {
+        return Ok(None)
+    }
+    check_unlocked(year, day, now)?;
+
+    let input = site()?.input(year, day)?;
+
+    let (path, data) = match key {
+        Some(key) => (crypt::encrypted_path(path), crypt::encrypt(key, &input)?),
+        None => (path.to_path_buf(), input.into_bytes()),
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("Unable to create {}: {}", dir.display(), err))?;
+    }
+    fs::write(&path, data).map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+
+    Ok(Some(path))
+}
+
+/// A stand-in for the site that answers requests on localhost with canned responses
+#[cfg(test)]
+pub mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves the given responses in order, one per connection, then stops. Returns the server's base URL along
+    /// with a handle that gives back every request it received, headers and all.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses.into_iter().map(|(status, body)| {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8(request_body).unwrap());
+
+                let response = format!("HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                                       status, body.len(), body);
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+                request
+            }).collect()
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use aoc_common::crypt::{self, Key};
+
+    use super::test_server::serve;
+    use super::{check_unlocked, fetch, unlock_time, Site, UreqHttp};
+
+    fn site(base_url: &str) -> Site {
+        Site::new(Box::new(UreqHttp::new()), base_url, "abc123")
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2024-12-01 05:00:00 UTC
+        assert_eq!(unlock_time(2024, 1), UNIX_EPOCH + Duration::from_secs(1_733_029_200));
+        assert_eq!(unlock_time(2020, 25), UNIX_EPOCH + Duration::from_secs(1_608_872_400));
+    }
+
+    #[test]
+    fn test_check_unlocked() {
+        let unlock = unlock_time(2024, 6);
+
+        assert!(check_unlocked(2024, 6, unlock).is_ok());
+        assert_eq!(check_unlocked(2024, 6, unlock - Duration::from_secs(90 * 60)),
+                   Err("2024 day 6 unlocks in 1h 30m".to_string()));
+        assert!(check_unlocked(2024, 26, unlock).is_err());
+        assert!(check_unlocked(2014, 1, unlock).is_err());
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+        let path = dir.join("2024").join("day1_input.txt");
+        let now = unlock_time(2024, 1);
+
+        let (base_url, server) = serve(vec![(200, "3   4\n4   3\n")]);
+        assert_eq!(fetch(|| Ok(site(&base_url)), 2024, 1, &path, None, now), Ok(Some(path.clone())));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].to_lowercase().contains("cookie: session=abc123\r\n"));
+
+        let unreachable = || -> Result<Site, String> { panic!("A cached input shouldn't be downloaded again") };
+        assert_eq!(fetch(unreachable, 2024, 1, &path, None, now), Ok(None));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_encrypts_with_a_key() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-encrypted-{}", std::process::id()));
+        let path = dir.join("2024").join("day3_input.txt");
+        let encrypted = crypt::encrypted_path(&path);
+        let key = Key::generate().unwrap();
+        let now = unlock_time(2024, 3);
+
+        let (base_url, server) = serve(vec![(200, "mul(2,4)\n")]);
+        assert_eq!(fetch(|| Ok(site(&base_url)), 2024, 3, &path, Some(&key), now), Ok(Some(encrypted.clone())));
+        assert!(!path.exists());
+        assert_eq!(crypt::read_encrypted(&encrypted, &key), Ok("mul(2,4)\n".to_string()));
+        server.join().unwrap();
+
+        let unreachable = || -> Result<Site, String> { panic!("An encrypted input shouldn't be downloaded again") };
+        assert_eq!(fetch(unreachable, 2024, 3, &path, Some(&key), now), Ok(None));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let path = std::env::temp_dir().join(format!("aoc-fetch-errors-{}", std::process::id())).join("day2.txt");
+
+        let locked = || -> Result<Site, String> { panic!("A locked puzzle shouldn't be requested") };
+        assert!(fetch(locked, 2024, 2, &path, None, unlock_time(2024, 1)).is_err());
+
+        let (base_url, server) = serve(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+        let message = fetch(|| Ok(site(&base_url)), 2024, 2, &path, None, unlock_time(2024, 2)).unwrap_err();
+        assert_eq!(message, "The site didn't accept the session token (400)");
+        assert!(!path.exists());
+
+        server.join().unwrap();
+    }
+}
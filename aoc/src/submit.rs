@@ -0,0 +1,412 @@
+//! Submitting answers, for `aoc submit`, keeping a ledger of every attempt.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answers::Answers;
+use crate::site::Site;
+
+/// How the site responded to an answer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way
+    Wrong,
+    /// Sent too soon after the last attempt, so the answer wasn't checked
+    RateLimited,
+    /// The part has already been solved, or isn't open yet
+    WrongLevel,
+    /// A response the runner doesn't recognise
+    Unknown,
+}
+
+impl Outcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate-limited",
+            Outcome::WrongLevel => "wrong-level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Outcome> {
+        [Outcome::Correct, Outcome::TooHigh, Outcome::TooLow, Outcome::Wrong, Outcome::RateLimited,
+         Outcome::WrongLevel, Outcome::Unknown].into_iter().find(|outcome| outcome.label() == label)
+    }
+
+    /// Whether the site checked the answer and turned it down
+    fn is_rejection(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+/// What the site said about an answer
+#[derive(Clone, Debug, PartialEq)]
+pub struct Verdict {
+    pub outcome: Outcome,
+    /// How long the site asked to be left alone before the next attempt
+    pub wait: Duration,
+    /// The site's own words, without the markup
+    pub message: String,
+}
+
+/// The text of the page's main article, with the markup stripped and whitespace collapsed
+fn page_text(html: &str) -> String {
+    let article = html.split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// How long the page asks to wait, from either "You have 1m 5s left to wait" or "Please wait 5 minutes"
+fn wait_time(text: &str) -> Duration {
+    let text = text.to_lowercase();
+
+    if let Some((_, rest)) = text.split_once("you have ") {
+        if let Some((amount, _)) = rest.split_once(" left to wait") {
+            let seconds = amount.split_whitespace()
+                .map(|part| match part.split_at(part.len().saturating_sub(1)) {
+                    (minutes, "m") => minutes.parse::<u64>().unwrap_or(0) * 60,
+                    (seconds, "s") => seconds.parse::<u64>().unwrap_or(0),
+                    _ => 0,
+                })
+                .sum();
+            return Duration::from_secs(seconds)
+        }
+    }
+
+    if let Some((_, rest)) = text.split_once("wait ") {
+        let mut words = rest.split_whitespace();
+        let amount = match words.next() {
+            Some("one") => Some(1),
+            Some(amount) => amount.parse::<u64>().ok(),
+            None => None,
+        };
+        if let (Some(amount), Some(unit)) = (amount, words.next()) {
+            if unit.starts_with("minute") {
+                return Duration::from_secs(amount * 60)
+            }
+        }
+    }
+
+    Duration::ZERO
+}
+
+/// Works out what the page the site sent back after an answer says
+pub fn parse_response(html: &str) -> Verdict {
+    let message = page_text(html);
+
+    let outcome = if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Outcome::TooHigh
+        } else if message.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    };
+
+    Verdict { outcome, wait: wait_time(&message), message }
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// One answer sent to the site
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    /// When it was sent, in seconds since the Unix epoch
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_id: String,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// When the site will take another answer, in seconds since the Unix epoch
+    pub retry_after: u64,
+}
+
+/// The ledger kept at the root of the workspace
+pub fn default_ledger_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("submissions.tsv")
+}
+
+/// Every answer sent so far, as loaded from (and saved back to) the ledger file
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Loads the ledger at the given path, treating a missing file as an empty ledger
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("Unable to read {}: {}", path.display(), err)),
+        };
+
+        let attempts = parse_ledger(&contents).map_err(|message| format!("{}: {}", path.display(), message))?;
+
+        Ok(Ledger { path: path.to_path_buf(), attempts })
+    }
+
+    /// Refuses an answer the site is bound to turn down, or any answer while the site has asked for a break
+    pub fn check(&self, year: u16, day: u8, part: u8, input_id: &str, answer: &str, now: SystemTime)
+        -> Result<(), String> {
+        let now = unix_seconds(now);
+        if let Some(retry_after) = self.attempts.iter().map(|attempt| attempt.retry_after).max() {
+            if retry_after > now {
+                return Err(format!("The site asked for a break; try again in {}s", retry_after - now))
+            }
+        }
+
+        let attempts: Vec<&Attempt> = self.attempts.iter()
+            .filter(|attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part))
+            .filter(|attempt| attempt.input_id == input_id)
+            .collect();
+
+        if let Some(correct) = attempts.iter().find(|attempt| attempt.outcome == Outcome::Correct) {
+            return Err(format!("Already solved with {}", correct.answer))
+        }
+        if let Some(rejected) = attempts.iter().find(|attempt| attempt.answer == answer && attempt.outcome.is_rejection()) {
+            return Err(format!("{} was already rejected as {}", answer, rejected.outcome.label()))
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: Outcome| attempts.iter()
+                .filter(move |attempt| attempt.outcome == outcome)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok());
+
+            if let Some(high) = bound(Outcome::TooHigh).min().filter(|high| value >= *high) {
+                return Err(format!("{} can't be right, since {} was too high", answer, high))
+            }
+            if let Some(low) = bound(Outcome::TooLow).max().filter(|low| value <= *low) {
+                return Err(format!("{} can't be right, since {} was too low", answer, low))
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut contents = String::from("# time\tyear\tday\tpart\tinput\tanswer\toutcome\tretry_after\n");
+        for attempt in &self.attempts {
+            contents.push_str(&format!("{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n", attempt.time, attempt.year, attempt.day,
+                                       attempt.part, attempt.input_id, attempt.answer, attempt.outcome.label(),
+                                       attempt.retry_after));
+        }
+
+        fs::write(&self.path, contents).map_err(|err| format!("Unable to write {}: {}", self.path.display(), err))
+    }
+}
+
+fn parse_ledger(contents: &str) -> Result<Vec<Attempt>, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let invalid = |name: &str| format!("line {}: invalid {}", index + 1, name);
+
+            match line.split('\t').collect::<Vec<&str>>()[..] {
+                [time, year, day, part, input_id, answer, outcome, retry_after] => Ok(Attempt {
+                    time: time.parse().map_err(|_| invalid("time"))?,
+                    year: year.parse().map_err(|_| invalid("year"))?,
+                    day: day.parse().map_err(|_| invalid("day"))?,
+                    part: part.parse().map_err(|_| invalid("part"))?,
+                    input_id: input_id.to_string(),
+                    answer: answer.to_string(),
+                    outcome: Outcome::from_label(outcome).ok_or_else(|| invalid("outcome"))?,
+                    retry_after: retry_after.parse().map_err(|_| invalid("retry time"))?,
+                }),
+                _ => Err(format!("line {}: expected 8 fields", index + 1)),
+            }
+        })
+        .collect()
+}
+
+/// An answer to send, along with the input it was worked out from
+pub struct Submission<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_id: &'a str,
+    pub answer: &'a str,
+}
+
+/// Sends the answer unless the answers manifest or the ledger show there's no point, records the attempt in the
+/// ledger, and adds a correct answer to the manifest. The site is only connected to once the answer has passed
+/// those checks.
+pub fn submit(site: impl FnOnce() -> Result<Site, String>, ledger: &mut Ledger, answers: &mut Answers,
+              submission: &Submission, now: SystemTime) -> Result<Verdict, String> {
+    let Submission { year, day, part, input_id, answer } = *submission;
+
+    if let Some(expected) = answers.expected(year, day, part, input_id) {
+        return Err(if expected == answer {
+            format!("{} is already the recorded answer", answer)
+        } else {
+            format!("The recorded answer is {}, so {} is wrong", expected, answer)
+        })
+    }
+    ledger.check(year, day, part, input_id, answer, now)?;
+
+    let verdict = parse_response(&site()?.answer(year, day, part, answer)?);
+
+    ledger.record(Attempt {
+        time: unix_seconds(now),
+        year,
+        day,
+        part,
+        input_id: input_id.to_string(),
+        answer: answer.to_string(),
+        outcome: verdict.outcome,
+        retry_after: unix_seconds(now + verdict.wait),
+    });
+    ledger.save()?;
+
+    if verdict.outcome == Outcome::Correct {
+        answers.record(year, day, part, input_id, answer);
+        answers.save()?;
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{parse_ledger, parse_response, submit, Attempt, Ledger, Outcome, Submission};
+    use crate::answers::Answers;
+    use crate::site::test_server::serve;
+    use crate::site::{Site, UreqHttp};
+
+    const TOO_HIGH: &str = "<html><body><main><article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. \
+        <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article></main></body></html>";
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer to \
+        finding the Chief Historian.</p></article></main>";
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an \
+        answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    fn attempt(part: u8, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt { time: 100, year: 2024, day: 6, part, input_id: "abc".to_string(), answer: answer.to_string(),
+                  outcome, retry_after: 100 }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let verdict = parse_response(TOO_HIGH);
+        assert_eq!((verdict.outcome, verdict.wait), (Outcome::TooHigh, Duration::from_secs(60)));
+        assert!(verdict.message.starts_with("That's not the right answer; your answer is too high."));
+
+        assert_eq!(parse_response(CORRECT).outcome, Outcome::Correct);
+        assert_eq!(parse_response(RATE_LIMITED).outcome, Outcome::RateLimited);
+        assert_eq!(parse_response(RATE_LIMITED).wait, Duration::from_secs(65));
+        assert_eq!(parse_response("<article><p>That's not the right answer.  Please wait 5 minutes before \
+                                   trying again.</p></article>").wait, Duration::from_secs(300));
+        assert_eq!(parse_response("<article><p>You don't seem to be solving the right level.  Did you already \
+                                   complete it?</p></article>").outcome, Outcome::WrongLevel);
+        assert_eq!(parse_response("<html>Something else</html>").outcome, Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_ledger_refusals() {
+        let ledger = Ledger {
+            path: "submissions.tsv".into(),
+            attempts: vec![attempt(1, "5000", Outcome::TooHigh), attempt(1, "4000", Outcome::TooLow),
+                           attempt(1, "4500", Outcome::Wrong), attempt(2, "1697", Outcome::Correct)],
+        };
+        let now = UNIX_EPOCH + Duration::from_secs(200);
+        let check = |part: u8, answer: &str| ledger.check(2024, 6, part, "abc", answer, now);
+
+        assert!(check(1, "4988").is_ok());
+        assert_eq!(check(1, "4500"), Err("4500 was already rejected as wrong".to_string()));
+        assert_eq!(check(1, "5001"), Err("5001 can't be right, since 5000 was too high".to_string()));
+        assert_eq!(check(1, "4000"), Err("4000 was already rejected as too-low".to_string()));
+        assert_eq!(check(1, "3999"), Err("3999 can't be right, since 4000 was too low".to_string()));
+        assert_eq!(check(2, "1700"), Err("Already solved with 1697".to_string()));
+        assert!(ledger.check(2024, 6, 1, "def", "5001", now).is_ok());
+        assert!(ledger.check(2024, 6, 1, "abc", "4988", UNIX_EPOCH + Duration::from_secs(50)).is_err());
+    }
+
+    #[test]
+    fn test_parse_ledger() {
+        let attempts = parse_ledger("# header\n100\t2024\t6\t1\tabc\t5000\ttoo-high\t160\n").unwrap();
+        assert_eq!(attempts, vec![Attempt { retry_after: 160, ..attempt(1, "5000", Outcome::TooHigh) }]);
+
+        assert!(parse_ledger("100\t2024\t6\t1\tabc\t5000\tnope\t160\n").is_err());
+        assert!(parse_ledger("100\t2024\t6\t1\tabc\t5000\n").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut ledger = Ledger::load(&dir.join("submissions.tsv")).unwrap();
+        let mut answers = Answers::load(&dir.join("answers.tsv")).unwrap();
+
+        let (base_url, server) = serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let site = || Ok(Site::new(Box::new(UreqHttp::new()), &base_url, "abc123"));
+        let submission = |answer| Submission { year: 2024, day: 6, part: 1, input_id: "abc", answer };
+
+        let start = UNIX_EPOCH + Duration::from_secs(1_000);
+        let verdict = submit(site, &mut ledger, &mut answers, &submission("5000"), start).unwrap();
+        assert_eq!(verdict.outcome, Outcome::TooHigh);
+
+        let later = start + Duration::from_secs(30);
+        let unreachable = || -> Result<Site, String> { panic!("Refused answers shouldn't be sent") };
+        assert!(submit(unreachable, &mut ledger, &mut answers, &submission("4988"), later).is_err());
+
+        let later = start + Duration::from_secs(60);
+        assert!(submit(unreachable, &mut ledger, &mut answers, &submission("5100"), later).is_err());
+        let verdict = submit(site, &mut ledger, &mut answers, &submission("4988"), later).unwrap();
+        assert_eq!(verdict.outcome, Outcome::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/6/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=5000"));
+
+        assert_eq!(Ledger::load(&dir.join("submissions.tsv")).unwrap().attempts.len(), 2);
+        assert_eq!(Answers::load(&dir.join("answers.tsv")).unwrap().expected(2024, 6, 1, "abc"), Some("4988"));
+        assert!(submit(unreachable, &mut ledger, &mut answers, &submission("4988"), later).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}